@@ -7,7 +7,7 @@ use regex::Regex;
 use std::collections::HashSet;
 use syn::{Data, DeriveInput, Fields, Lit, LitStr, Type, parse_macro_input};
 
-#[proc_macro_derive(Harbor, attributes(harbor, header, response))]
+#[proc_macro_derive(Harbor, attributes(harbor, body, header, response))]
 pub fn derive_harbor(input: TokenStream) -> TokenStream {
     match derive_harbor_impl(parse_macro_input!(input as DeriveInput)) {
         Ok(ts) => ts.into(),
@@ -18,8 +18,11 @@ pub fn derive_harbor(input: TokenStream) -> TokenStream {
 fn derive_harbor_impl(input: DeriveInput) -> Result<proc_macro2::TokenStream, syn::Error> {
     let struct_name = &input.ident;
 
-    // --- Parse #[harbor(url = "...", response = Type)] or #[response(Type)]
+    // --- Parse #[harbor(url = "...", method = "...", anonymous, response = Type)] or
+    // #[response(Type)]
     let mut base_path = String::new();
+    let mut method: Option<LitStr> = None;
+    let mut anonymous = false;
     let mut response_type: Option<Type> = None;
 
     for attr in &input.attrs {
@@ -30,6 +33,10 @@ fn derive_harbor_impl(input: DeriveInput) -> Result<proc_macro2::TokenStream, sy
                     if let Lit::Str(s) = lit {
                         base_path = s.value();
                     }
+                } else if meta.path.is_ident("method") {
+                    method = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("anonymous") {
+                    anonymous = true;
                 } else if meta.path.is_ident("response") {
                     let ty: Type = meta.value()?.parse()?;
                     response_type = Some(ty);
@@ -47,6 +54,20 @@ fn derive_harbor_impl(input: DeriveInput) -> Result<proc_macro2::TokenStream, sy
     let response_type =
         response_type.expect("Missing #[harbor(response = T)] or #[response(T)] attribute");
 
+    // --- Bind the request to its HTTP method
+    let method = method.ok_or_else(|| {
+        syn::Error::new_spanned(struct_name, "missing #[harbor(method = \"...\")] attribute")
+    })?;
+    let method_ident = match method.value().to_uppercase().as_str() {
+        m @ ("DELETE" | "GET" | "HEAD" | "PATCH" | "POST" | "PUT") => format_ident!("{}", m),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                method,
+                "expected one of \"DELETE\", \"GET\", \"HEAD\", \"PATCH\", \"POST\" or \"PUT\"",
+            ));
+        }
+    };
+
     // --- Find placeholders {field}
     let re = Regex::new(r"\{(\w+)\}").unwrap();
     let placeholders: Vec<String> = re
@@ -58,6 +79,10 @@ fn derive_harbor_impl(input: DeriveInput) -> Result<proc_macro2::TokenStream, sy
     // --- Inspect fields
     let mut header_push_tokens = Vec::new();
     let mut query_push_tokens = Vec::new();
+    let mut field_names = HashSet::new();
+    let mut double_encoded = HashSet::new();
+    let mut body_field: Option<syn::Ident> = None;
+    let mut body_key_tokens = Vec::new();
 
     if let Data::Struct(data) = &input.data
         && let Fields::Named(fields) = &data.fields
@@ -65,11 +90,67 @@ fn derive_harbor_impl(input: DeriveInput) -> Result<proc_macro2::TokenStream, sy
         for field in &fields.named {
             let ident = field.ident.clone().unwrap();
             let name_str = ident.to_string();
+            field_names.insert(name_str.clone());
 
             let mut header_name: Option<String> = None;
             let mut is_header = false;
+            let mut is_body = false;
+            let mut body_key: Option<String> = None;
 
             for attr in &field.attrs {
+                if attr.path().is_ident("body") {
+                    is_body = true;
+
+                    // #[body(field)] or #[body(rename = "...")] adds the field to a JSON object
+                    if !matches!(attr.meta, syn::Meta::Path(_)) {
+                        attr.parse_nested_meta(|meta| {
+                            if meta.path.is_ident("field") {
+                                body_key = Some(name_str.clone());
+                                Ok(())
+                            } else if meta.path.is_ident("rename") {
+                                let lit: Lit = meta.value()?.parse()?;
+                                if let Lit::Str(s) = lit {
+                                    body_key = Some(s.value());
+                                    Ok(())
+                                } else {
+                                    Err(syn::Error::new_spanned(
+                                        lit,
+                                        "expected string literal for #[body(rename = ...)]",
+                                    ))
+                                }
+                            } else {
+                                Err(syn::Error::new_spanned(
+                                    meta.path,
+                                    "only #[body], #[body(field)] or #[body(rename = \"...\")] is allowed",
+                                ))
+                            }
+                        })?;
+                    }
+
+                    let conflict = match &body_key {
+                        Some(_) => body_field.is_some(),
+                        None => body_field.is_some() || !body_key_tokens.is_empty(),
+                    };
+                    if conflict {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "either mark one field as #[body], or mark fields as #[body(field)]",
+                        ));
+                    }
+                }
+                if attr.path().is_ident("harbor") {
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("double_encode") {
+                            double_encoded.insert(name_str.clone());
+                            Ok(())
+                        } else {
+                            Err(syn::Error::new_spanned(
+                                meta.path,
+                                "only #[harbor(double_encode)] is allowed on fields",
+                            ))
+                        }
+                    })?;
+                }
                 if attr.path().is_ident("header") {
                     is_header = true;
 
@@ -95,7 +176,33 @@ fn derive_harbor_impl(input: DeriveInput) -> Result<proc_macro2::TokenStream, sy
                 }
             }
 
-            if is_header {
+            if let Some(key) = body_key {
+                let key_lit = LitStr::new(&key, Span::call_site());
+
+                let is_option = matches!(&field.ty,
+                    Type::Path(tp) if tp.path.segments.iter().any(|seg| seg.ident == "Option")
+                );
+
+                if is_option {
+                    body_key_tokens.push(quote! {
+                        if let Some(v) = self.#ident.as_ref() {
+                            body.insert(
+                                #key_lit.to_string(),
+                                ::serde_json::to_value(v).map_err(|e| e.to_string())?,
+                            );
+                        }
+                    });
+                } else {
+                    body_key_tokens.push(quote! {
+                        body.insert(
+                            #key_lit.to_string(),
+                            ::serde_json::to_value(&self.#ident).map_err(|e| e.to_string())?,
+                        );
+                    });
+                }
+            } else if is_body {
+                body_field = Some(ident);
+            } else if is_header {
                 let header_name = header_name.unwrap_or_else(|| name_str.replace('_', "-"));
                 let header_name_lit = LitStr::new(&header_name, Span::call_site());
 
@@ -140,16 +247,73 @@ fn derive_harbor_impl(input: DeriveInput) -> Result<proc_macro2::TokenStream, sy
     for ph in &placeholders {
         let ident = format_ident!("{}", ph);
         let ph_lit = LitStr::new(&format!("{{{}}}", ph), Span::call_site());
+        let segment = if double_encoded.contains(ph) {
+            quote! {
+                crate::request::encode_path_segment(
+                    &crate::request::encode_path_segment(&self.#ident.to_string()),
+                )
+            }
+        } else {
+            quote! { crate::request::encode_path_segment(&self.#ident.to_string()) }
+        };
         path_replace_tokens.push(quote! {
-            path = path.replace(#ph_lit, &self.#ident.to_string());
+            path = path.replace(#ph_lit, &#segment);
         });
     }
 
+    // --- Body: either the #[body] field is serialized as JSON, or the #[body(field)] fields are
+    // collected into a JSON object
+    let body_fn = match &body_field {
+        Some(ident) => quote! {
+            fn body(&self) -> Result<Option<String>, String> {
+                ::serde_json::to_string(&self.#ident)
+                    .map(Some)
+                    .map_err(|e| e.to_string())
+            }
+        },
+        None if !body_key_tokens.is_empty() => quote! {
+            fn body(&self) -> Result<Option<String>, String> {
+                let mut body = ::serde_json::Map::new();
+                #(#body_key_tokens)*
+
+                ::serde_json::to_string(&body)
+                    .map(Some)
+                    .map_err(|e| e.to_string())
+            }
+        },
+        None => quote! {},
+    };
+
+    // --- Pagination: requests with both `page` and `page_size` can be paged through
+    let paginated_impl = if field_names.contains("page") && field_names.contains("page_size") {
+        quote! {
+            impl Paginated for #struct_name {
+                fn page(&self) -> Option<i32> {
+                    self.page
+                }
+
+                fn page_size(&self) -> Option<i32> {
+                    self.page_size
+                }
+
+                fn set_page(&mut self, page: i32) {
+                    self.page = Some(page);
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // --- Impl
     let expanded = quote! {
         impl HarborRequest for #struct_name {
             type Response = #response_type;
 
+            const METHOD: ::reqwest::Method = ::reqwest::Method::#method_ident;
+
+            const ANONYMOUS: bool = #anonymous;
+
             fn to_url(&self) -> String {
                 let mut path = #base_path.to_string();
                 #(#path_replace_tokens)*
@@ -177,7 +341,11 @@ fn derive_harbor_impl(input: DeriveInput) -> Result<proc_macro2::TokenStream, sy
                 }
                 Ok(header_map)
             }
+
+            #body_fn
         }
+
+        #paginated_impl
     };
 
     Ok(expanded)
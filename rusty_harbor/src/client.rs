@@ -1,19 +1,29 @@
-use std::env;
+use std::{
+    env,
+    sync::{PoisonError, RwLock},
+};
 
 use dotenv::dotenv;
 
-use crate::{DEFAULT_PASS, DEFAULT_USER, HARBOR_HOST, client::error::ClientError};
+use crate::{
+    DEFAULT_PASS, DEFAULT_USER, HARBOR_CA_BUNDLE, HARBOR_HOST,
+    client::{auth::Auth, builder::HarborClientBuilder, error::ClientError, retry::RetryPolicy},
+};
 
+pub mod auth;
+pub mod builder;
 mod dispatch;
-mod error;
+pub mod error;
+mod paginate;
+pub mod retry;
 #[cfg(test)]
 mod tests;
 
-/// Robot account
+/// Client for the Harbor API, authenticating with (robot) account credentials.
 pub struct HarborClient {
     base_url: String,
-    username: String,
-    password: String,
+    auth: RwLock<Auth>,
+    retry: RetryPolicy,
     client: reqwest::Client,
 }
 
@@ -24,23 +34,44 @@ impl Default for HarborClient {
 }
 
 impl HarborClient {
+    /// Create a client from the environment (or a `.env` file), using the environment variables
+    /// `u` and `p` for the credentials.
+    ///
+    /// If `HARBOR_CA_BUNDLE` is set, the CA certificates in that PEM file are trusted as well.
     pub fn from_env(u: &str, p: &str) -> Result<Self, ClientError> {
         dotenv().ok();
-        HarborClient::new(env::var(HARBOR_HOST)?, env::var(u)?, env::var(p)?)
+        let mut builder = HarborClient::builder(env::var(HARBOR_HOST)?)
+            .auth(Auth::basic(env::var(u)?, env::var(p)?));
+        if let Ok(path) = env::var(HARBOR_CA_BUNDLE) {
+            builder = builder.ca_bundle_file(path);
+        }
+        builder.build()
     }
 
+    /// Create a client with the default configuration, which verifies server certificates and
+    /// uses basic authentication.
     pub fn new(base_url: String, username: String, password: String) -> Result<Self, ClientError> {
-        let client = HarborClient {
-            base_url,
-            username,
-            password,
-            client: reqwest::Client::builder()
-                //.add_root_certificate(cert)
-                // TODO!: Figure out certification
-                .danger_accept_invalid_certs(true)
-                .build()?,
-        };
-
-        Ok(client)
+        HarborClient::builder(base_url)
+            .auth(Auth::basic(username, password))
+            .build()
+    }
+
+    /// Create a builder to configure the client, e.g. its authentication and TLS settings.
+    pub fn builder(base_url: impl Into<String>) -> HarborClientBuilder {
+        HarborClientBuilder::new(base_url.into())
+    }
+
+    /// The credentials the client currently authenticates with.
+    pub fn auth(&self) -> Auth {
+        self.auth
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replace the credentials the client authenticates with, e.g. after rotating a secret. This
+    /// applies to all requests sent afterwards, also through shared references to the client.
+    pub fn set_auth(&self, auth: Auth) {
+        *self.auth.write().unwrap_or_else(PoisonError::into_inner) = auth;
     }
 }
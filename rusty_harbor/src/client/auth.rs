@@ -0,0 +1,72 @@
+use std::fmt;
+
+use reqwest::RequestBuilder;
+
+/// The credentials a [`HarborClient`](crate::client::HarborClient) authenticates with.
+///
+/// They can be replaced at runtime with [`set_auth`](crate::client::HarborClient::set_auth), e.g.
+/// after rotating the secret of a robot account.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub enum Auth {
+    /// Send requests without credentials, e.g. to read public projects.
+    #[default]
+    Anonymous,
+    /// Basic authentication with the username and password of a (robot) account.
+    Basic { username: String, password: String },
+    /// Authentication with a bearer token.
+    Bearer(String),
+    /// Authentication of an OIDC (SSO) user with the username and the CLI secret from the user
+    /// profile in Harbor.
+    OidcCliSecret { username: String, secret: String },
+}
+
+impl Auth {
+    pub fn basic(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Auth::Basic {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Auth::Bearer(token.into())
+    }
+
+    pub fn oidc_cli_secret(username: impl Into<String>, secret: impl Into<String>) -> Self {
+        Auth::OidcCliSecret {
+            username: username.into(),
+            secret: secret.into(),
+        }
+    }
+
+    /// Add the credentials to a request.
+    pub(super) fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+        match self {
+            Auth::Anonymous => request,
+            Auth::Basic { username, password } => request.basic_auth(username, Some(password)),
+            Auth::Bearer(token) => request.bearer_auth(token),
+            // Harbor accepts the CLI secret of OIDC users as the password for basic authentication
+            Auth::OidcCliSecret { username, secret } => request.basic_auth(username, Some(secret)),
+        }
+    }
+}
+
+/// Never print passwords, tokens or secrets.
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Auth::Anonymous => f.write_str("Anonymous"),
+            Auth::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &"<redacted>")
+                .finish(),
+            Auth::Bearer(_) => f.debug_tuple("Bearer").field(&"<redacted>").finish(),
+            Auth::OidcCliSecret { username, .. } => f
+                .debug_struct("OidcCliSecret")
+                .field("username", username)
+                .field("secret", &"<redacted>")
+                .finish(),
+        }
+    }
+}
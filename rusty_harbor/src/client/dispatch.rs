@@ -1,27 +1,60 @@
-use reqwest::{Method, RequestBuilder, Response};
+use std::{any::type_name, time::Instant};
+
+use reqwest::{
+    Method, RequestBuilder, Response,
+    header::{
+        AUTHORIZATION, CONTENT_TYPE, COOKIE, HeaderMap, HeaderName, HeaderValue, LOCATION,
+        PROXY_AUTHORIZATION,
+    },
+};
 use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::{Instrument, field::Empty};
 
 use crate::{
-    client::{HarborClient, error::ClientError},
+    client::{HarborClient, error::ClientError, retry::is_retryable_status},
     request::HarborRequest,
+    response::{HarborResponse, X_REQUEST_ID},
 };
 
+/// Headers carrying credentials, which are never logged.
+const SENSITIVE_HEADERS: [HeaderName; 3] = [AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION];
+
 /// This macro allows for custom implementations that call [`dispatch`](HarborClient::dispatch)
 /// with a different [`Method`].
+///
+/// These are escape hatches; prefer [`send`](HarborClient::send), which uses the method the
+/// request is bound to.
 macro_rules! http_method_fn {
     ($name:ident, $method:expr) => {
         pub async fn $name<R: HarborRequest>(
             &self,
             request: R,
         ) -> Result<R::Response, ClientError> {
-            let request_builder = self.request($method, request)?;
-            let response = self.dispatch(request_builder).await?;
-            deserialize_response($method, response).await
+            self.send_with_method($method, request).await
         }
     };
 }
 
 impl HarborClient {
+    /// Send a request using the HTTP method it is bound to through
+    /// [`METHOD`](HarborRequest::METHOD).
+    pub async fn send<R: HarborRequest>(&self, request: R) -> Result<R::Response, ClientError> {
+        self.send_with_meta(request)
+            .await
+            .map(HarborResponse::into_body)
+    }
+
+    /// Send a request like [`send`](HarborClient::send), but keep the status and headers of the
+    /// response alongside the deserialized body.
+    pub async fn send_with_meta<R: HarborRequest>(
+        &self,
+        request: R,
+    ) -> Result<HarborResponse<R::Response>, ClientError> {
+        let url = self.api_url(&request.to_url());
+        self.execute(R::METHOD, url, &request).await
+    }
+
     // Implement dispatchers for various HTTP request methods.
     http_method_fn!(delete, Method::DELETE);
     http_method_fn!(get, Method::GET);
@@ -30,27 +63,75 @@ impl HarborClient {
     http_method_fn!(post, Method::POST);
     http_method_fn!(put, Method::PUT);
 
-    fn request<R: HarborRequest>(
+    async fn send_with_method<R: HarborRequest>(
         &self,
         method: Method,
         request: R,
-    ) -> Result<RequestBuilder, ClientError> {
-        // Define the API url with the url encoded request
-        let url = format!("{}/api/v2.0/{}", self.base_url, request.to_url());
+    ) -> Result<R::Response, ClientError> {
+        let url = self.api_url(&request.to_url());
+        self.execute(method, url, &request)
+            .await
+            .map(HarborResponse::into_body)
+    }
+
+    /// Create, dispatch and deserialize a request to the given `url`.
+    ///
+    /// This happens within a `harbor_request` span, recording the method, path, status, latency
+    /// and request id of the request.
+    pub(super) async fn execute<R: HarborRequest>(
+        &self,
+        method: Method,
+        url: String,
+        request: &R,
+    ) -> Result<HarborResponse<R::Response>, ClientError> {
+        let span = tracing::info_span!(
+            "harbor_request",
+            method = %method,
+            path = %url.strip_prefix(&self.base_url).unwrap_or(&url),
+            status = Empty,
+            latency_ms = Empty,
+            request_id = Empty,
+        );
+
+        async move {
+            let request_builder = self.request(method.clone(), url, request)?;
+            let response = self.dispatch(request_builder).await?;
+            deserialize_response(method, response).await
+        }
+        .instrument(span)
+        .await
+    }
 
+    /// Define the API url for the url encoded `path` of a request.
+    pub(super) fn api_url(&self, path: &str) -> String {
+        format!("{}/api/v2.0/{}", self.base_url, path)
+    }
+
+    pub(super) fn request<R: HarborRequest>(
+        &self,
+        method: Method,
+        url: String,
+        request: &R,
+    ) -> Result<RequestBuilder, ClientError> {
         // Create the request
-        let request = self
+        let mut request_builder = self
             .client
             .request(method.clone(), url)
-            .headers(request.headers().map_err(ClientError::Header)?)
-            .basic_auth(&self.username, Some(&self.password));
+            .headers(request.headers().map_err(ClientError::Header)?);
 
-        if let Some(cloned) = request.try_clone() {
-            let req = cloned.build()?;
-            println!("{:#?}", req);
+        // Authenticate, unless the endpoint does not require it
+        if !R::ANONYMOUS {
+            request_builder = self.auth().apply(request_builder);
         }
 
-        Ok(request)
+        // Attach the JSON body if the request has one
+        if let Some(body) = request.body().map_err(ClientError::Body)? {
+            request_builder = request_builder
+                .header(CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+
+        Ok(request_builder)
     }
 
     /// Dispatch an HTTP request to the Harbor API.
@@ -58,37 +139,139 @@ impl HarborClient {
     /// It will form the API url using the [`base_url`](HarborClient::base_url) and the
     /// [`to_url`](ToUrl::to_url) function of the request of type `R`.
     ///
-    /// Then it sends the request using basic authorization with
-    /// [`username`](HarborClient::username) and [`password`](HarborClient::password), check if the
-    /// response is OK and (if so) deserialize it into type `T`.
-    async fn dispatch(&self, request: RequestBuilder) -> Result<Response, ClientError> {
-        // Send the request and wait for the response
-        let response = request.send().await?;
-
-        // Get the status of the response
-        let status = response.status();
-
-        // Check if the response is OK
-        if !status.is_success() {
-            let message: String = response.text().await?;
-            return Err(ClientError::Response { status, message });
-        }
+    /// Then it sends the request authenticated with the [`Auth`](crate::client::auth::Auth) of
+    /// the client, check if the response is OK and (if so) deserialize it into type `T`.
+    ///
+    /// Failed attempts are retried according to the
+    /// [`RetryPolicy`](crate::client::retry::RetryPolicy) of the client.
+    pub(super) async fn dispatch(&self, request: RequestBuilder) -> Result<Response, ClientError> {
+        let mut request = request.build()?;
+        let mut attempt = 1;
+        let start = Instant::now();
+
+        loop {
+            // Keep a copy of the request for a next attempt, if it may be retried
+            let next = request
+                .try_clone()
+                .filter(|_| self.retry.allows_retry(request.method(), attempt));
+
+            tracing::debug!(attempt, headers = ?redacted(request.headers()), "sending request");
+
+            // Send the request and wait for the response
+            let (response, next) = match (self.client.execute(request).await, next) {
+                (Ok(response), next) => (response, next),
+                (Err(e), Some(next)) if e.is_connect() => {
+                    let backoff = self.retry.backoff(attempt, None);
+                    tracing::warn!(attempt, error = %e, ?backoff, "connection failed, retrying");
+                    tokio::time::sleep(backoff).await;
+                    (request, attempt) = (next, attempt + 1);
+                    continue;
+                }
+                (Err(e), _) => {
+                    tracing::error!(attempt, error = %e, "request failed");
+                    return Err(e.into());
+                }
+            };
+
+            // Get the status of the response
+            let status = response.status();
 
-        Ok(response)
+            // Check if the response is OK
+            if !status.is_success() {
+                if let Some(next) = next.filter(|_| is_retryable_status(status)) {
+                    let backoff = self.retry.backoff(attempt, Some(response.headers()));
+                    tracing::warn!(attempt, status = status.as_u16(), ?backoff, "retrying");
+                    tokio::time::sleep(backoff).await;
+                    (request, attempt) = (next, attempt + 1);
+                    continue;
+                }
+
+                record_response(&response, start);
+                let message: String = response.text().await?;
+                tracing::warn!(attempt, message, "harbor responded with an error");
+                return Err(ClientError::response(status, message));
+            }
+
+            record_response(&response, start);
+            tracing::debug!(attempt, "received response");
+
+            return Ok(response);
+        }
     }
 }
 
 async fn deserialize_response<R: DeserializeOwned>(
     method: Method,
     response: Response,
-) -> Result<R, ClientError> {
-    // Deserialize the response in the expected type
-    serde_json::from_str::<R>(&{
-        match method {
-            // Special case for HEAD since it won't return any body
-            Method::HEAD => String::from("null"),
-            _ => response.text().await?,
+) -> Result<HarborResponse<R>, ClientError> {
+    // Keep the status and headers, since reading the body consumes the response
+    let status = response.status();
+    let headers = response.headers().clone();
+
+    let text = match method {
+        // Special case for HEAD since it won't return any body
+        Method::HEAD => String::new(),
+        _ => response.text().await?,
+    };
+
+    // Write requests (e.g. POST, PUT, DELETE) often respond without a body
+    let body = if text.trim().is_empty() {
+        let location = headers.get(LOCATION).and_then(|value| value.to_str().ok());
+        match (serde_json::from_str::<R>("null"), location) {
+            // Requests that create a resource can deserialize from its location instead
+            (Err(_), Some(location)) => {
+                serde_json::from_value::<R>(Value::String(location.to_string()))?
+            }
+            (result, _) => result?,
         }
+    } else if is_plain_text(&headers) {
+        // Plain text responses (e.g. logs) deserialize from a JSON string
+        serde_json::from_value::<R>(Value::String(text)).inspect_err(|e| {
+            tracing::error!(error = %e, r#type = type_name::<R>(), "failed to deserialize response");
+        })?
+    } else {
+        // Deserialize the response in the expected type
+        serde_json::from_str::<R>(&text).inspect_err(|e| {
+            tracing::error!(error = %e, r#type = type_name::<R>(), "failed to deserialize response");
+        })?
+    };
+
+    Ok(HarborResponse {
+        status,
+        headers,
+        body,
     })
-    .map_err(ClientError::from)
+}
+
+/// Record the outcome of a request on the current `harbor_request` span.
+fn record_response(response: &Response, start: Instant) {
+    let span = tracing::Span::current();
+    span.record("status", response.status().as_u16());
+    span.record("latency_ms", start.elapsed().as_millis() as u64);
+    if let Some(request_id) = response
+        .headers()
+        .get(X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+    {
+        span.record("request_id", request_id);
+    }
+}
+
+/// A copy of the headers with the credentials replaced, so they can be logged.
+fn redacted(headers: &HeaderMap) -> HeaderMap {
+    let mut headers = headers.clone();
+    for name in SENSITIVE_HEADERS {
+        if headers.contains_key(&name) {
+            headers.insert(name, HeaderValue::from_static("<redacted>"));
+        }
+    }
+    headers
+}
+
+/// Whether the response has a plain text body instead of JSON.
+fn is_plain_text(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/plain"))
 }
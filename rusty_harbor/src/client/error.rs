@@ -1,8 +1,12 @@
 use reqwest::StatusCode;
+use serde::Deserialize;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum ClientError {
+    #[error("error serializing the request body: {0}")]
+    Body(String),
+
     #[error("not the expected format: {0}")]
     Deserialize(#[from] serde_json::Error),
 
@@ -12,9 +16,145 @@ pub enum ClientError {
     #[error("{0}")]
     Header(String),
 
+    #[error("unexpected link to the next page: {0}")]
+    Link(String),
+
+    #[error("error reading file: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("error creating the client: {0}")]
     Reqwest(#[from] reqwest::Error),
 
     #[error("error response ({status}): {message}")]
-    Response { status: StatusCode, message: String },
+    Response {
+        status: StatusCode,
+        /// The errors parsed from the response body; empty if the body has another format.
+        errors: HarborErrors,
+        /// The raw response body.
+        message: String,
+    },
+}
+
+impl ClientError {
+    /// Create a [`Response`](ClientError::Response) error from an unsuccessful response body.
+    pub(crate) fn response(status: StatusCode, message: String) -> Self {
+        let errors = serde_json::from_str(&message).unwrap_or_default();
+        ClientError::Response {
+            status,
+            errors,
+            message,
+        }
+    }
+
+    /// The status code of an error response.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            ClientError::Response { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// The errors Harbor reported in an error response.
+    pub fn errors(&self) -> &[HarborError] {
+        match self {
+            ClientError::Response { errors, .. } => &errors.errors,
+            _ => &[],
+        }
+    }
+
+    /// Whether Harbor reported an error with the given code, or responded with the given status
+    /// when it did not report any error.
+    fn is(&self, code: HarborErrorCode, status: StatusCode) -> bool {
+        match self.errors() {
+            [] => self.status() == Some(status),
+            errors => errors.iter().any(|error| error.code == code),
+        }
+    }
+
+    /// The requested resource does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.is(HarborErrorCode::NotFound, StatusCode::NOT_FOUND)
+    }
+
+    /// The resource already exists or conflicts with an existing one.
+    pub fn is_conflict(&self) -> bool {
+        self.is(HarborErrorCode::Conflict, StatusCode::CONFLICT)
+    }
+
+    /// The request was not authenticated.
+    pub fn is_unauthorized(&self) -> bool {
+        self.is(HarborErrorCode::Unauthorized, StatusCode::UNAUTHORIZED)
+    }
+
+    /// The authenticated user lacks the permission for the request.
+    pub fn is_forbidden(&self) -> bool {
+        self.is(HarborErrorCode::Forbidden, StatusCode::FORBIDDEN)
+    }
+
+    /// A precondition of the request was not met, e.g. a project that still has repositories.
+    pub fn is_precondition_failed(&self) -> bool {
+        self.is(
+            HarborErrorCode::Precondition,
+            StatusCode::PRECONDITION_FAILED,
+        )
+    }
+}
+
+/// The error payload Harbor returns with unsuccessful responses, e.g.
+/// `{"errors":[{"code":"NOT_FOUND","message":"project foo not found"}]}`.
+#[derive(Debug, Default, Deserialize)]
+pub struct HarborErrors {
+    /// The reported errors.
+    #[serde(default)]
+    pub errors: Vec<HarborError>,
+}
+
+/// A single error reported by Harbor.
+#[derive(Debug, Deserialize)]
+pub struct HarborError {
+    /// The error code.
+    pub code: HarborErrorCode,
+    /// The error message.
+    #[serde(default)]
+    pub message: String,
+}
+
+/// The code of an error reported by Harbor.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(from = "String")]
+pub enum HarborErrorCode {
+    BadRequest,
+    Conflict,
+    Denied,
+    Forbidden,
+    MethodNotAllowed,
+    NotFound,
+    Precondition,
+    TooManyRequests,
+    Unauthorized,
+    Unknown,
+    Unsupported,
+    ViolateForeignKeyConstraint,
+    /// Any code that is not covered by the other variants.
+    Other(String),
+}
+
+impl From<String> for HarborErrorCode {
+    fn from(code: String) -> Self {
+        match code.as_str() {
+            "BAD_REQUEST" => HarborErrorCode::BadRequest,
+            "CONFLICT" => HarborErrorCode::Conflict,
+            "DENIED" => HarborErrorCode::Denied,
+            "FORBIDDEN" => HarborErrorCode::Forbidden,
+            "METHOD_NOT_ALLOWED" => HarborErrorCode::MethodNotAllowed,
+            "NOT_FOUND" => HarborErrorCode::NotFound,
+            "PRECONDITION" => HarborErrorCode::Precondition,
+            "TOO_MANY_REQUEST" => HarborErrorCode::TooManyRequests,
+            "UNAUTHORIZED" => HarborErrorCode::Unauthorized,
+            "UNKNOWN" => HarborErrorCode::Unknown,
+            "UNSUPPORTED" => HarborErrorCode::Unsupported,
+            "VIOLATE_FOREIGN_KEY_CONSTRAINT" => HarborErrorCode::ViolateForeignKeyConstraint,
+            _ => HarborErrorCode::Other(code),
+        }
+    }
 }
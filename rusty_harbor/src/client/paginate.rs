@@ -0,0 +1,106 @@
+use futures::{Stream, TryStreamExt, stream};
+use reqwest::header::{HeaderMap, LINK};
+
+use crate::{
+    client::{HarborClient, error::ClientError},
+    request::Paginated,
+};
+
+/// The progress of a [`paginate`](HarborClient::paginate) stream.
+struct PageState<R> {
+    /// The request that is being paged through.
+    request: R,
+    /// The link to the next page, as advertised in the `Link` header.
+    next: Option<String>,
+    /// The number of items that have been fetched so far.
+    fetched: u64,
+}
+
+impl HarborClient {
+    /// Page through all results of a list request, yielding the items one by one.
+    ///
+    /// The first page is requested as described by `request`. After that, the `Link` header with
+    /// `rel="next"` is followed until Harbor no longer advertises a next page. When the response
+    /// has no `Link` header at all, the `X-Total-Count` header is used to determine whether
+    /// another page has to be requested.
+    pub fn paginate<R, T>(&self, request: R) -> impl Stream<Item = Result<T, ClientError>>
+    where
+        R: Paginated<Response = Vec<T>>,
+    {
+        let state = PageState {
+            request,
+            next: None,
+            fetched: 0,
+        };
+
+        stream::try_unfold(Some(state), move |state| async move {
+            let Some(mut state) = state else {
+                return Ok::<_, ClientError>(None);
+            };
+
+            // Follow the link to the next page if there is one
+            let url = match state.next.take() {
+                Some(link) => self.link_url(&link)?,
+                None => self.api_url(&state.request.to_url()),
+            };
+
+            let response = self.execute(R::METHOD, url, &state.request).await?;
+
+            let next = next_link(&response.headers);
+            let has_link = response.headers.contains_key(LINK);
+            let total = response.total_count();
+
+            let items = response.into_body();
+            state.fetched += items.len() as u64;
+
+            let state = if next.is_some() {
+                state.next = next;
+                Some(state)
+            } else if !has_link && !items.is_empty() && total.is_some_and(|t| state.fetched < t) {
+                let page = state.request.page().unwrap_or(1);
+                state.request.set_page(page + 1);
+                Some(state)
+            } else {
+                None
+            };
+
+            Ok(Some((stream::iter(items.into_iter().map(Ok)), state)))
+        })
+        .try_flatten()
+    }
+
+    /// Rebuild the link to the next page on top of the API URL of the client. Harbor advertises it
+    /// as a path from its own root, e.g. `/api/v2.0/projects?page=2`, which loses any path prefix
+    /// the base URL has when Harbor is served behind a reverse proxy.
+    fn link_url(&self, link: &str) -> Result<String, ClientError> {
+        link.strip_prefix("/api/v2.0/")
+            .map(|path| self.api_url(path))
+            .ok_or_else(|| ClientError::Link(link.to_string()))
+    }
+
+    /// Page through all results of a list request and collect them into a single vector.
+    pub async fn collect_all<R, T>(&self, request: R) -> Result<Vec<T>, ClientError>
+    where
+        R: Paginated<Response = Vec<T>>,
+    {
+        self.paginate(request).try_collect().await
+    }
+}
+
+/// Get the path of the next page from a `Link` header, e.g.
+/// `</api/v2.0/projects?page=1&page_size=10>; rel="prev", </api/v2.0/projects?page=3&page_size=10>; rel="next"`.
+pub(super) fn next_link(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|link| {
+            let (target, params) = link.trim().split_once(';')?;
+            let is_next = params
+                .split(';')
+                .any(|param| param.trim().replace(' ', "") == "rel=\"next\"");
+            let target = target.trim().strip_prefix('<')?.strip_suffix('>')?;
+            is_next.then(|| target.to_string())
+        })
+}
@@ -0,0 +1,125 @@
+use std::time::{Duration, SystemTime};
+
+use reqwest::{
+    Method, StatusCode,
+    header::{HeaderMap, RETRY_AFTER},
+};
+
+/// When and how often a [`HarborClient`](crate::client::HarborClient) retries a failed request.
+///
+/// Only requests with an idempotent method (`GET`, `HEAD`, `OPTIONS`, `PUT` and `DELETE`) are
+/// retried, when Harbor responds with `429 Too Many Requests`, `502 Bad Gateway`,
+/// `503 Service Unavailable` or `504 Gateway Timeout`, or when no connection could be made.
+///
+/// Between attempts the client waits as long as the `Retry-After` header of the response asks
+/// for, or otherwise an exponentially growing backoff with full jitter. Either way it never waits
+/// longer than the [`max_backoff`](RetryPolicy::max_backoff).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, backing off from 200 milliseconds up to 10 seconds, with jitter.
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends every request only once.
+    pub fn never() -> Self {
+        RetryPolicy::default().max_attempts(1)
+    }
+
+    /// The number of times a request is sent at most, including the first attempt.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// The backoff before the second attempt, which doubles for every attempt after that.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// The upper bound of the exponential backoff, and of the wait a `Retry-After` header asks
+    /// for.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Wait a random duration between zero and the backoff, so that clients failing at the same
+    /// time do not retry at the same time as well.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Whether another attempt may follow the given (1-based) attempt.
+    pub(super) fn allows_retry(&self, method: &Method, attempt: u32) -> bool {
+        attempt < self.max_attempts && is_idempotent(method)
+    }
+
+    /// The time to wait after the given (1-based) attempt failed.
+    pub(super) fn backoff(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        if let Some(retry_after) = headers.and_then(retry_after) {
+            return retry_after.min(self.max_backoff);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            backoff.mul_f64(fastrand::f64())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Whether a response with this status is worth retrying.
+pub(super) fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+/// Parse the `Retry-After` header, either in seconds or as an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
@@ -1,25 +1,56 @@
+use std::{
+    io::Write,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
 use dotenv::from_filename;
-use reqwest::Method;
+use futures::StreamExt;
+use rcgen::{BasicConstraints, CertificateParams, CertifiedIssuer, DnType, IsCa, KeyPair};
+use reqwest::{
+    Method, StatusCode,
+    header::{HeaderMap, HeaderValue, LINK, RETRY_AFTER},
+};
+use serde_json::json;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    task::JoinHandle,
+};
+use tokio_native_tls::{TlsAcceptor, native_tls};
 
 use crate::{
-    client::HarborClient,
+    client::{
+        HarborClient,
+        auth::Auth,
+        error::{ClientError, HarborErrorCode},
+        paginate::next_link,
+        retry::RetryPolicy,
+    },
     request::{
         HarborRequest,
         v2::{
+            artifact::get::{GetArtifact, GetArtifacts, GetVulnerabilitiesAddition},
             health::get::GetHealth,
+            label::get::GetLabels,
             project::{
                 get::{GetProjectArtifacts, GetProjectSummary, GetProjects},
                 head::HeadProjects,
+                post::CreateProject,
             },
+            repository::get::GetProjectRepositories,
+            scan::{get::GetScanReportLog, post::ScanArtifact},
             search::get::GetSearch,
             statistics::get::GetStatistics,
         },
     },
+    response::v2::project::types::Severity,
+    testing::{MockHarbor, PROJECT_NAME},
 };
 
-/// Name of the project to be used in below integration tests
-const PROJECT_NAME: &str = "<PROJECT_NAME>";
-
 #[test]
 fn harbor_client_can_be_initialized_with_different_credentials() {
     // Load the template file, containing "OTHER_USER" and "OTHER_PASS"
@@ -29,15 +60,663 @@ fn harbor_client_can_be_initialized_with_different_credentials() {
     let client = HarborClient::from_env("OTHER_USER", "OTHER_PASS").unwrap();
 
     // Assert the values from .env.template are being applied
-    assert_eq!("<OTHER_USERNAME>", client.username);
-    assert_eq!("<OTHER_PASSWORD>", client.password);
+    assert_eq!(
+        Auth::basic("<OTHER_USERNAME>", "<OTHER_PASSWORD>"),
+        client.auth()
+    );
+}
+
+#[test]
+fn next_page_is_parsed_from_link_header() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        LINK,
+        HeaderValue::from_static(
+            "</api/v2.0/projects?page=1&page_size=10>; rel=\"prev\" , \
+             </api/v2.0/projects?page=3&page_size=10>; rel=\"next\"",
+        ),
+    );
+    assert_eq!(
+        Some("/api/v2.0/projects?page=3&page_size=10".to_string()),
+        next_link(&headers)
+    );
+}
+
+#[test]
+fn last_page_has_no_next_link() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        LINK,
+        HeaderValue::from_static("</api/v2.0/projects?page=1&page_size=10>; rel=\"prev\""),
+    );
+    assert_eq!(None, next_link(&headers));
+}
+
+#[test]
+fn error_response_is_parsed_into_harbor_errors() {
+    let message = r#"{"errors":[{"code":"NOT_FOUND","message":"project foo not found"}]}"#;
+    let error = ClientError::response(StatusCode::NOT_FOUND, message.to_string());
+    assert_eq!(HarborErrorCode::NotFound, error.errors()[0].code);
+    assert_eq!("project foo not found", error.errors()[0].message);
+    assert!(error.is_not_found());
+    assert!(!error.is_conflict());
+}
+
+#[test]
+fn unknown_error_code_is_kept() {
+    let message = r#"{"errors":[{"code":"DIGEST_INVALID","message":"invalid digest"}]}"#;
+    let error = ClientError::response(StatusCode::BAD_REQUEST, message.to_string());
+    assert_eq!(
+        HarborErrorCode::Other("DIGEST_INVALID".to_string()),
+        error.errors()[0].code
+    );
+}
+
+#[test]
+fn error_response_without_payload_falls_back_to_status() {
+    let error = ClientError::response(StatusCode::CONFLICT, "conflict".to_string());
+    assert!(error.errors().is_empty());
+    assert!(error.is_conflict());
+}
+
+/// PEM encoded certificates of a test CA and a `localhost` server certificate signed by it.
+struct TestPki {
+    ca_cert: String,
+    server_cert: String,
+    server_key: String,
+}
+
+fn test_pki() -> TestPki {
+    let mut ca_params = CertificateParams::new(Vec::<String>::new()).unwrap();
+    ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    ca_params
+        .distinguished_name
+        .push(DnType::CommonName, "rusty_harbor test CA");
+    let ca = CertifiedIssuer::self_signed(ca_params, KeyPair::generate().unwrap()).unwrap();
+
+    let server_key = KeyPair::generate().unwrap();
+    let mut server_params = CertificateParams::new(vec!["localhost".to_string()]).unwrap();
+    server_params
+        .distinguished_name
+        .push(DnType::CommonName, "localhost");
+    let server_cert = server_params.signed_by(&server_key, &ca).unwrap();
+
+    TestPki {
+        ca_cert: ca.pem(),
+        server_cert: server_cert.pem(),
+        server_key: server_key.serialize_pem(),
+    }
+}
+
+/// Serve a healthy [`GetHealth`] response over TLS on `localhost`, returning the base url.
+async fn serve_health_over_tls(pki: &TestPki) -> String {
+    let identity =
+        native_tls::Identity::from_pkcs8(pki.server_cert.as_bytes(), pki.server_key.as_bytes())
+            .unwrap();
+    let acceptor = TlsAcceptor::from(native_tls::TlsAcceptor::new(identity).unwrap());
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            // Handshakes fail when the client rejects the certificate
+            let Ok(mut stream) = acceptor.accept(stream).await else {
+                continue;
+            };
+            let mut buffer = [0; 4096];
+            let _ = stream.read(&mut buffer).await;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{HEALTHY}",
+                HEALTHY.len()
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+            let _ = stream.shutdown().await;
+        }
+    });
+
+    format!("https://localhost:{port}")
+}
+
+#[tokio::test]
+async fn untrusted_certificate_is_rejected_by_default() {
+    let pki = test_pki();
+    let base_url = serve_health_over_tls(&pki).await;
+    let client = HarborClient::new(base_url, "user".into(), "pass".into()).unwrap();
+    let request = GetHealth::builder().build().unwrap();
+    let error = client.send(request).await.unwrap_err();
+    assert!(matches!(error, ClientError::Reqwest(_)));
+}
+
+#[tokio::test]
+async fn certificate_signed_by_ca_bundle_is_trusted() {
+    let pki = test_pki();
+    let base_url = serve_health_over_tls(&pki).await;
+    let client = HarborClient::builder(base_url)
+        .ca_bundle_pem(pki.ca_cert.as_bytes())
+        .build()
+        .unwrap();
+    let request = GetHealth::builder().build().unwrap();
+    let health = client.send(request).await.unwrap();
+    assert_eq!("healthy", health.status);
+}
+
+#[tokio::test]
+async fn invalid_certificate_is_accepted_when_opted_in() {
+    let pki = test_pki();
+    let base_url = serve_health_over_tls(&pki).await;
+    let client = HarborClient::builder(base_url)
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap();
+    let request = GetHealth::builder().build().unwrap();
+    assert!(client.send(request).await.is_ok());
+}
+
+#[test]
+fn client_identity_is_loaded_from_pem() {
+    let pki = test_pki();
+    let client = HarborClient::builder("https://localhost")
+        .identity_pem(pki.server_cert.as_bytes(), pki.server_key.as_bytes())
+        .build();
+    assert!(client.is_ok());
+}
+
+#[test]
+fn invalid_ca_bundle_file_fails_to_build() {
+    let client = HarborClient::builder("https://localhost")
+        .ca_bundle_file("does/not/exist.pem")
+        .build();
+    assert!(matches!(client, Err(ClientError::Io(_))));
+}
+
+/// Body of a healthy [`GetHealth`] response.
+const HEALTHY: &str = r#"{"status":"healthy","components":[]}"#;
+
+/// Serve a single JSON response over plain HTTP after `delay`, returning the base url and a
+/// handle resolving to the raw request that was received.
+async fn serve_once(delay: Duration, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buffer = [0; 4096];
+        let read = stream.read(&mut buffer).await.unwrap();
+        tokio::time::sleep(delay).await;
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+        String::from_utf8_lossy(&buffer[..read]).to_lowercase()
+    });
+
+    (format!("http://127.0.0.1:{port}"), handle)
+}
+
+#[tokio::test]
+async fn default_user_agent_is_sent() {
+    let (base_url, received) = serve_once(Duration::ZERO, HEALTHY).await;
+    let client = HarborClient::new(base_url, "user".into(), "pass".into()).unwrap();
+    client
+        .send(GetHealth::builder().build().unwrap())
+        .await
+        .unwrap();
+    let expected = format!("user-agent: rusty_harbor/{}", env!("CARGO_PKG_VERSION"));
+    assert!(received.await.unwrap().contains(&expected));
+}
+
+#[tokio::test]
+async fn custom_user_agent_is_sent() {
+    let (base_url, received) = serve_once(Duration::ZERO, HEALTHY).await;
+    let client = HarborClient::builder(base_url)
+        .user_agent("cleanup-job/1.0")
+        .pool_max_idle_per_host(1)
+        .pool_idle_timeout(None)
+        .build()
+        .unwrap();
+    client
+        .send(GetHealth::builder().build().unwrap())
+        .await
+        .unwrap();
+    assert!(
+        received
+            .await
+            .unwrap()
+            .contains("user-agent: cleanup-job/1.0")
+    );
+}
+
+#[tokio::test]
+async fn slow_response_times_out() {
+    let (base_url, _received) = serve_once(Duration::from_secs(5), HEALTHY).await;
+    let client = HarborClient::builder(base_url)
+        .read_timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let error = client
+        .send(GetHealth::builder().build().unwrap())
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ClientError::Reqwest(e) if e.is_timeout()));
+}
+
+#[tokio::test]
+async fn requests_are_sent_through_proxy() {
+    let (proxy_url, received) = serve_once(Duration::ZERO, HEALTHY).await;
+    let client = HarborClient::builder("http://harbor.invalid")
+        .proxy(proxy_url)
+        .build()
+        .unwrap();
+    client
+        .send(GetHealth::builder().build().unwrap())
+        .await
+        .unwrap();
+    assert!(
+        received
+            .await
+            .unwrap()
+            .starts_with("get http://harbor.invalid/api/v2.0/health")
+    );
+}
+
+#[tokio::test]
+async fn pre_built_client_is_used() {
+    let (base_url, received) = serve_once(Duration::ZERO, HEALTHY).await;
+    let client = HarborClient::builder(base_url)
+        .client(
+            reqwest::Client::builder()
+                .user_agent("injected")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    client
+        .send(GetHealth::builder().build().unwrap())
+        .await
+        .unwrap();
+    assert!(received.await.unwrap().contains("user-agent: injected"));
+}
+
+#[tokio::test]
+async fn health_is_requested_without_credentials() {
+    let (base_url, received) = serve_once(Duration::ZERO, HEALTHY).await;
+    let client = HarborClient::new(base_url, "user".into(), "pass".into()).unwrap();
+    client
+        .send(GetHealth::builder().build().unwrap())
+        .await
+        .unwrap();
+    assert!(!received.await.unwrap().contains("authorization:"));
+}
+
+#[tokio::test]
+async fn bearer_token_is_sent() {
+    let (base_url, received) = serve_once(Duration::ZERO, "[]").await;
+    let client = HarborClient::builder(base_url)
+        .auth(Auth::bearer("some-token"))
+        .build()
+        .unwrap();
+    client
+        .send(GetProjects::builder().build().unwrap())
+        .await
+        .unwrap();
+    assert!(
+        received
+            .await
+            .unwrap()
+            .contains("authorization: bearer some-token")
+    );
+}
+
+#[tokio::test]
+async fn credentials_can_be_swapped_at_runtime() {
+    let (base_url, received) = serve_once(Duration::ZERO, "[]").await;
+    let client = HarborClient::new(base_url, "user".into(), "old".into()).unwrap();
+    client.set_auth(Auth::oidc_cli_secret("user", "new"));
+    assert_eq!(Auth::oidc_cli_secret("user", "new"), client.auth());
+    client
+        .send(GetProjects::builder().build().unwrap())
+        .await
+        .unwrap();
+    // "user:new" in base64
+    assert!(
+        received
+            .await
+            .unwrap()
+            .contains("authorization: basic dxnlcjpuzxc=")
+    );
+}
+
+#[test]
+fn credentials_are_redacted_from_debug_output() {
+    let auth = format!("{:?}", Auth::basic("robot$ci", "super-secret"));
+    assert!(auth.contains("robot$ci"));
+    assert!(!auth.contains("super-secret"));
+}
+
+/// Serve the given raw HTTP responses over plain HTTP, one per connection, returning the base url
+/// and the number of requests that were received.
+async fn serve_responses(responses: Vec<String>) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let received = Arc::new(AtomicUsize::new(0));
+
+    let counter = received.clone();
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = [0; 4096];
+            let _ = stream.read(&mut buffer).await;
+            counter.fetch_add(1, Ordering::SeqCst);
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    (format!("http://127.0.0.1:{port}"), received)
+}
+
+fn raw_response(status: &str, headers: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\n{headers}content-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+fn quick_retries() -> RetryPolicy {
+    RetryPolicy::default().initial_backoff(Duration::from_millis(1))
+}
+
+#[tokio::test]
+async fn bad_gateway_is_retried() {
+    let (base_url, received) = serve_responses(vec![
+        raw_response("502 Bad Gateway", "", ""),
+        raw_response("200 OK", "", HEALTHY),
+    ])
+    .await;
+    let client = HarborClient::builder(base_url)
+        .retry(quick_retries())
+        .build()
+        .unwrap();
+    client
+        .send(GetHealth::builder().build().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(2, received.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn retries_stop_after_max_attempts() {
+    let unavailable = raw_response("503 Service Unavailable", "", "");
+    let (base_url, received) = serve_responses(vec![unavailable; 3]).await;
+    let client = HarborClient::builder(base_url)
+        .retry(quick_retries().max_attempts(3))
+        .build()
+        .unwrap();
+    let error = client
+        .send(GetHealth::builder().build().unwrap())
+        .await
+        .unwrap_err();
+    assert_eq!(Some(StatusCode::SERVICE_UNAVAILABLE), error.status());
+    assert_eq!(3, received.load(Ordering::SeqCst));
 }
 
 #[tokio::test]
-async fn get_projects_from_workspace() {
+async fn non_idempotent_requests_are_not_retried() {
+    let unavailable = raw_response("503 Service Unavailable", "", "");
+    let (base_url, received) = serve_responses(vec![unavailable; 2]).await;
+    let client = HarborClient::builder(base_url)
+        .retry(quick_retries())
+        .build()
+        .unwrap();
+    let request = CreateProject::builder("test").build().unwrap();
+    assert!(client.send(request).await.is_err());
+    assert_eq!(1, received.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn retry_after_is_honored() {
+    let (base_url, _) = serve_responses(vec![
+        raw_response("429 Too Many Requests", "retry-after: 1\r\n", ""),
+        raw_response("200 OK", "", HEALTHY),
+    ])
+    .await;
+    let client = HarborClient::builder(base_url)
+        .retry(quick_retries())
+        .build()
+        .unwrap();
+    let start = Instant::now();
+    client
+        .send(GetHealth::builder().build().unwrap())
+        .await
+        .unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[test]
+fn backoff_grows_exponentially_up_to_max() {
+    let policy = RetryPolicy::default()
+        .initial_backoff(Duration::from_millis(100))
+        .max_backoff(Duration::from_millis(500))
+        .jitter(false);
+    assert_eq!(Duration::from_millis(100), policy.backoff(1, None));
+    assert_eq!(Duration::from_millis(200), policy.backoff(2, None));
+    assert_eq!(Duration::from_millis(400), policy.backoff(3, None));
+    assert_eq!(Duration::from_millis(500), policy.backoff(4, None));
+}
+
+#[test]
+fn jitter_stays_within_backoff() {
+    let policy = RetryPolicy::default().initial_backoff(Duration::from_millis(100));
+    assert!(policy.backoff(1, None) <= Duration::from_millis(100));
+}
+
+#[test]
+fn retry_after_date_in_the_past_is_immediate() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        RETRY_AFTER,
+        HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+    );
+    assert_eq!(
+        Duration::ZERO,
+        RetryPolicy::default().backoff(1, Some(&headers))
+    );
+}
+
+#[test]
+fn retry_after_is_capped_by_max_backoff() {
+    let mut headers = HeaderMap::new();
+    headers.insert(RETRY_AFTER, HeaderValue::from_static("3600"));
+    let policy = RetryPolicy::default().max_backoff(Duration::from_secs(10));
+    assert_eq!(Duration::from_secs(10), policy.backoff(1, Some(&headers)));
+}
+
+/// Writer collecting the output of a tracing subscriber.
+#[derive(Clone, Default)]
+struct Logs(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Logs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn requests_are_traced_without_credentials() {
+    let logs = Logs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let (base_url, _) = serve_responses(vec![
+        raw_response("503 Service Unavailable", "", ""),
+        raw_response("200 OK", "x-request-id: abc-123\r\n", "[]"),
+    ])
+    .await;
+    let client = HarborClient::builder(base_url)
+        .auth(Auth::basic("robot$ci", "super-secret"))
+        .retry(quick_retries())
+        .build()
+        .unwrap();
+    client
+        .send(GetProjects::builder().build().unwrap())
+        .await
+        .unwrap();
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(logs.contains("harbor_request{method=GET path=/api/v2.0/projects"));
+    assert!(logs.contains("status=503"));
+    assert!(logs.contains("status=200"));
+    assert!(logs.contains("request_id=\"abc-123\""));
+    assert!(logs.contains("<redacted>"));
+    // "robot$ci:super-secret" in base64
+    assert!(!logs.contains("cm9ib3QkY2k6c3VwZXItc2VjcmV0"));
+}
+
+#[tokio::test]
+async fn get_projects() {
     let request = GetProjects::builder().page_size(50).build().unwrap();
-    let projects = test_get(request).await;
-    assert!(!projects.is_empty());
+    let projects = test_send(request).await;
+    assert_eq!(2, projects.len());
+}
+
+#[tokio::test]
+async fn paginate_projects() {
+    let harbor = MockHarbor::start().await.unwrap();
+    let request = GetProjects::builder().page_size(1).build().unwrap();
+    let projects: Vec<_> = harbor.client().paginate(request).collect().await;
+    assert_eq!(2, projects.len());
+    assert!(projects.iter().all(Result::is_ok));
+    assert_eq!(2, harbor.requests().len());
+}
+
+/// Serve the given raw responses in order, recording the request line of every request.
+async fn serve_recorded(responses: Vec<String>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let received = Arc::new(Mutex::new(Vec::new()));
+
+    let recorded = received.clone();
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = [0; 4096];
+            let read = stream.read(&mut buffer).await.unwrap_or_default();
+            let request = String::from_utf8_lossy(&buffer[..read]);
+            let line = request.lines().next().unwrap_or_default().to_string();
+            recorded.lock().unwrap().push(line);
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    (format!("http://127.0.0.1:{port}"), received)
+}
+
+#[tokio::test]
+async fn paginate_keeps_the_path_prefix_of_the_base_url() {
+    let (origin, received) = serve_recorded(vec![
+        raw_response(
+            "200 OK",
+            "link: </api/v2.0/labels?page=2&page_size=1&scope=g>; rel=\"next\"\r\n",
+            r#"[{"id":1}]"#,
+        ),
+        raw_response("200 OK", "", r#"[{"id":2}]"#),
+    ])
+    .await;
+    // Harbor behind a prefix-stripping reverse proxy, advertising links from its own root
+    let client = HarborClient::builder(format!("{origin}/harbor/"))
+        .build()
+        .unwrap();
+    let request = GetLabels::global().page_size(1).build().unwrap();
+    let labels = client.collect_all(request).await.unwrap();
+    assert_eq!(2, labels.len());
+    assert_eq!(
+        vec![
+            "GET /harbor/api/v2.0/labels?page_size=1&scope=g HTTP/1.1",
+            "GET /harbor/api/v2.0/labels?page=2&page_size=1&scope=g HTTP/1.1",
+        ],
+        *received.lock().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn paginate_rejects_links_outside_the_api() {
+    let (origin, _) = serve_recorded(vec![raw_response(
+        "200 OK",
+        "link: </v2/_catalog?n=1&last=a>; rel=\"next\"\r\n",
+        r#"[{"id":1}]"#,
+    )])
+    .await;
+    let client = HarborClient::builder(origin).build().unwrap();
+    let request = GetLabels::global().page_size(1).build().unwrap();
+    let error = client.collect_all(request).await.unwrap_err();
+    assert!(matches!(error, ClientError::Link(link) if link == "/v2/_catalog?n=1&last=a"));
+}
+
+#[tokio::test]
+async fn collect_all_project_artifacts() {
+    let harbor = MockHarbor::start().await.unwrap();
+    let request = GetProjectArtifacts::builder(PROJECT_NAME)
+        .page_size(1)
+        .build()
+        .unwrap();
+    let artifacts = harbor.client().collect_all(request).await.unwrap();
+    assert_eq!(2, artifacts.len());
+}
+
+#[tokio::test]
+async fn paginate_keeps_repository_names_double_encoded() {
+    let artifact = |id: i64| {
+        json!({
+            "id": id,
+            "project_id": 1,
+            "repository_name": "library/team/app",
+            "digest": format!("sha256:{id}"),
+        })
+    };
+    let harbor = MockHarbor::builder()
+        .artifacts(vec![artifact(1), artifact(2)])
+        .start()
+        .await
+        .unwrap();
+    let request = GetArtifacts::builder(PROJECT_NAME, "team/app")
+        .page_size(1)
+        .build()
+        .unwrap();
+    let artifacts = harbor.client().collect_all(request).await.unwrap();
+    assert_eq!(2, artifacts.len());
+
+    let requests = harbor.requests();
+    assert_eq!(2, requests.len());
+    assert!(
+        requests.iter().all(|request| request.path
+            == "/api/v2.0/projects/library/repositories/team%252Fapp/artifacts")
+    );
+    assert_eq!(Some("page=2&page_size=1"), requests[1].query.as_deref());
+}
+
+#[tokio::test]
+async fn get_projects_with_meta() {
+    let harbor = MockHarbor::start().await.unwrap();
+    let request = GetProjects::builder()
+        .request_id("SomeID")
+        .page_size(1)
+        .build()
+        .unwrap();
+    let response = harbor.client().send_with_meta(request).await.unwrap();
+    assert!(response.status.is_success());
+    assert_eq!(Some("SomeID"), response.request_id());
+    assert_eq!(Some(2), response.total_count());
 }
 
 #[tokio::test]
@@ -47,69 +726,159 @@ async fn get_project_summary() {
         .is_resource_name(true)
         .build()
         .unwrap();
-    let _project_summary = test_get(request).await;
+    let project_summary = test_send(request).await;
+    assert_eq!(Some(2), project_summary.repo_count);
 }
 
 #[tokio::test]
 async fn get_project_artifacts() {
     let request = GetProjectArtifacts::builder(PROJECT_NAME).build().unwrap();
-    let artifacts = test_get(request).await;
+    let artifacts = test_send(request).await;
     assert!(!artifacts.is_empty());
 }
 
 #[tokio::test]
 async fn head_projects() {
     let request = HeadProjects::builder(PROJECT_NAME).build().unwrap();
-    let _ = test_head(request).await;
+    let _ = test_send(request).await;
+}
+
+#[tokio::test]
+async fn get_project_repositories() {
+    let request = GetProjectRepositories::builder(PROJECT_NAME)
+        .build()
+        .unwrap();
+    let harbor = MockHarbor::start().await.unwrap();
+    let client = harbor.client();
+    let repositories = client.send(request).await.unwrap();
+    assert!(!repositories.is_empty());
+
+    // The name of a repository contains its project, which requests take separately
+    let repository = &repositories[0];
+    assert_eq!(
+        format!("{PROJECT_NAME}/{}", repository.repository_name()),
+        repository.name
+    );
+    let request = GetArtifacts::builder(PROJECT_NAME, repository.repository_name())
+        .build()
+        .unwrap();
+    assert!(!client.send(request).await.unwrap().is_empty());
 }
 
 #[tokio::test]
 async fn get_health() {
     let request = GetHealth::builder().build().unwrap();
-    let _ = test_get(request).await;
+    let health = test_send(request).await;
+    assert_eq!("healthy", health.status);
 }
 
 #[tokio::test]
 async fn get_search() {
     let request = GetSearch::builder(PROJECT_NAME).build().unwrap();
-    let _ = test_get(request).await;
+    let search = test_send(request).await;
+    assert_eq!(1, search.project.unwrap().len());
 }
 
 #[tokio::test]
 async fn get_statistics() {
     let request = GetStatistics::builder().build().unwrap();
-    let _ = test_get(request).await;
+    let statistics = test_send(request).await;
+    assert_eq!(2, statistics.total_project_count);
 }
 
-async fn test_get<R: HarborRequest>(request: R) -> R::Response {
-    test_request(request, Method::GET).await
+#[tokio::test]
+async fn requests_are_recorded_by_mock() {
+    let harbor = MockHarbor::start().await.unwrap();
+    let request = GetProjects::builder().name("lib").build().unwrap();
+    harbor.client().send(request).await.unwrap();
+
+    let requests = harbor.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!(Method::GET, requests[0].method);
+    assert_eq!("/api/v2.0/projects", requests[0].path);
+    assert_eq!(Some("name=lib"), requests[0].query.as_deref());
+    assert!(requests[0].header("authorization").is_some());
 }
 
-async fn test_head<R: HarborRequest>(request: R) -> R::Response {
-    test_request(request, Method::HEAD).await
+#[tokio::test]
+async fn missing_project_is_not_found_on_mock() {
+    let harbor = MockHarbor::start().await.unwrap();
+    let request = GetProjectSummary::builder("missing").build().unwrap();
+    let error = harbor.client().send(request).await.unwrap_err();
+    assert!(error.is_not_found());
 }
 
-async fn test_request<R: HarborRequest>(request: R, method: Method) -> R::Response {
-    // Initialize a default client (using valid .env credentials)
-    let client = HarborClient::default();
-    println!("1");
-    // Send the request and deserialize the response
-    let response = match method {
-        Method::DELETE => client.delete(request).await,
-        Method::GET => client.get(request).await,
-        Method::HEAD => client.head(request).await,
-        Method::PATCH => client.patch(request).await,
-        Method::POST => client.post(request).await,
-        Method::PUT => client.put(request).await,
-        _ => panic!("Unsupported method: {method}"),
-    };
+#[tokio::test]
+async fn created_project_is_served_by_mock() {
+    let harbor = MockHarbor::builder()
+        .projects(Vec::new())
+        .start()
+        .await
+        .unwrap();
+    let client = harbor.client();
 
-    // Print the response
-    println!("{response:?}");
+    let request = CreateProject::builder("new").build().unwrap();
+    let created = client.send(request).await.unwrap();
+    assert_eq!(Some(1), created.id());
 
-    // Verify the response is correct
-    assert!(response.is_ok());
+    let request = CreateProject::builder("new").build().unwrap();
+    assert!(client.send(request).await.unwrap_err().is_conflict());
+
+    let request = HeadProjects::builder("new").build().unwrap();
+    assert!(client.send(request).await.is_ok());
+}
+
+#[tokio::test]
+async fn scanned_artifact_has_vulnerability_report() {
+    let harbor = MockHarbor::start().await.unwrap();
+    let client = harbor.client();
+
+    let request = ScanArtifact::builder(PROJECT_NAME, "alpine", "3.20")
+        .build()
+        .unwrap();
+    client.send(request).await.unwrap();
+
+    let request = GetArtifact::builder(PROJECT_NAME, "alpine", "3.20")
+        .with_scan_overview(true)
+        .build()
+        .unwrap();
+    let artifact = client.send(request).await.unwrap();
+    let summary = artifact
+        .scan_overview
+        .unwrap()
+        .into_values()
+        .next()
+        .unwrap();
+    assert!(summary.scan_status.unwrap().is_finished());
+
+    let request = GetVulnerabilitiesAddition::builder(PROJECT_NAME, "alpine", "3.20")
+        .build()
+        .unwrap();
+    let reports = client.send(request).await.unwrap();
+    let report = reports.into_values().next().unwrap();
+    assert_eq!(2, report.at_least(Severity::High).count());
+
+    let report_id = summary.report_id.unwrap();
+    let request = GetScanReportLog::builder(PROJECT_NAME, "alpine", "3.20", report_id)
+        .build()
+        .unwrap();
+    let log = client.send(request).await.unwrap();
+    assert!(log.contains("finished successfully"));
+}
 
+async fn test_send<R: HarborRequest>(request: R) -> R::Response {
+    // Start a mock Harbor seeded with fixtures
+    let harbor = MockHarbor::start().await.unwrap();
+    // Send the request using its own method and deserialize the response
+    let response = harbor.client().send(request).await;
+    // Verify the response is correct
+    assert!(response.is_ok(), "{response:?}");
     // Return unwrapped response
     response.unwrap()
 }
+
+#[test]
+fn requests_are_bound_to_their_method() {
+    assert_eq!(Method::GET, GetProjects::METHOD);
+    assert_eq!(Method::HEAD, HeadProjects::METHOD);
+}
@@ -1,7 +1,10 @@
 pub mod client;
 pub mod request;
 pub mod response;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 pub const HARBOR_HOST: &str = "HARBOR_HOST";
+pub const HARBOR_CA_BUNDLE: &str = "HARBOR_CA_BUNDLE";
 pub const DEFAULT_USER: &str = "DEFAULT_USER";
 pub const DEFAULT_PASS: &str = "DEFAULT_PASS";
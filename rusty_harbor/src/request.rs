@@ -1,10 +1,67 @@
-use reqwest::header::HeaderMap;
+use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
+use reqwest::{Method, header::HeaderMap};
 use serde::de::DeserializeOwned;
 
+pub mod query;
+pub mod sort;
 pub mod v2;
 
+/// Characters that have to be percent-encoded when a value is used as a single path segment.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
 pub trait HarborRequest {
     type Response: DeserializeOwned + std::fmt::Debug;
+    /// The HTTP method the request is bound to, used by [`send`](crate::client::HarborClient::send).
+    const METHOD: Method;
+    /// Whether the endpoint is sent without credentials, because it does not require
+    /// authentication. Set with `#[harbor(anonymous)]`.
+    const ANONYMOUS: bool = false;
     fn to_url(&self) -> String;
     fn headers(&self) -> Result<HeaderMap, String>;
+    /// The JSON body of the request, if it has any.
+    ///
+    /// The [`Harbor`](derive_harbor::Harbor) derive serializes a single field marked with
+    /// `#[body]` (usually a nested struct) as the whole body, or collects all fields marked with
+    /// `#[body(field)]` or `#[body(rename = "...")]` into a JSON object. Fields without a value
+    /// are left out of that object.
+    fn body(&self) -> Result<Option<String>, String> {
+        Ok(None)
+    }
+}
+
+/// Marker for list requests that can be paged through using the `page` and `page_size` query
+/// parameters.
+///
+/// It is implemented by the [`Harbor`](derive_harbor::Harbor) derive for every request that has
+/// both a `page` and a `page_size` field, and allows the request to be used with
+/// [`paginate`](crate::client::HarborClient::paginate) and
+/// [`collect_all`](crate::client::HarborClient::collect_all).
+pub trait Paginated: HarborRequest {
+    /// The page number the request currently points at.
+    fn page(&self) -> Option<i32>;
+    /// The size of per page.
+    fn page_size(&self) -> Option<i32>;
+    /// Point the request at a different page.
+    fn set_page(&mut self, page: i32);
+}
+
+/// Percent-encode a value so it can be used as a single segment of a request path.
+///
+/// Harbor expects repository names, which can contain slashes, to be encoded twice (e.g.
+/// `library/nginx` becomes `library%252Fnginx`); such fields are marked with
+/// `#[harbor(double_encode)]` and passed through this function twice by the
+/// [`Harbor`](derive_harbor::Harbor) derive.
+pub fn encode_path_segment(value: &str) -> String {
+    utf8_percent_encode(value, PATH_SEGMENT).to_string()
 }
@@ -0,0 +1,220 @@
+use std::{
+    fmt,
+    ops::{RangeFrom, RangeInclusive, RangeToInclusive},
+};
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+
+/// The format Harbor expects times in when querying resources.
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Builder for the `q` query parameter of list requests, e.g.
+/// [`GetProjects`](crate::request::v2::project::get::GetProjects).
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rusty_harbor::request::query::Query;
+///
+/// let start = Utc.with_ymd_and_hms(2020, 4, 9, 2, 36, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2020, 4, 10, 2, 36, 0).unwrap();
+/// let query = Query::new()
+///     .eq("name", "foo")
+///     .fuzzy("tags", "v1")
+///     .range("push_time", start..=end);
+///
+/// assert_eq!(
+///     r#"name="foo",tags=~"v1",push_time=[2020-04-09 02:36:00~2020-04-10 02:36:00]"#,
+///     query.to_string()
+/// );
+/// ```
+///
+/// It converts into the `String` the `q` fields take, so it can be passed to their builders
+/// directly.
+///
+/// Harbor has no way to escape values: it splits the query on every `,`, and list values on
+/// every space, before it looks at quotes. Values containing a comma (or, in a list, a space)
+/// cannot be matched; they are passed on as they are and Harbor will split them apart.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Query {
+    terms: Vec<String>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Query::default()
+    }
+
+    /// Match resources where `key` is exactly `value` (`k=v`).
+    pub fn eq(self, key: &str, value: impl Into<QueryValue>) -> Self {
+        self.term(key, format!("{}", value.into()))
+    }
+
+    /// Match resources where `key` contains `value` (`k=~v`).
+    pub fn fuzzy(self, key: &str, value: impl Into<QueryValue>) -> Self {
+        self.term(key, format!("~{}", value.into()))
+    }
+
+    /// Match resources where `key` lies within `range` (`k=[min~max]`). Harbor includes both
+    /// bounds, so only inclusive ranges are accepted, which may leave a side open (e.g.
+    /// `start..`).
+    ///
+    /// ```compile_fail
+    /// use rusty_harbor::request::query::Query;
+    ///
+    /// // Harbor would include 10, which the exclusive range leaves out
+    /// Query::new().range("size", 1..10);
+    /// ```
+    pub fn range<V: Into<QueryValue>>(self, key: &str, range: impl InclusiveRange<V>) -> Self {
+        let bound = |bound: Option<V>| bound.map(|value| value.into().to_string());
+        let (min, max) = range.bounds();
+        let (min, max) = (
+            bound(min).unwrap_or_default(),
+            bound(max).unwrap_or_default(),
+        );
+        self.term(key, format!("[{min}~{max}]"))
+    }
+
+    /// Match resources where `key` is any of the `values` (`k={v1 v2 v3}`). Values must not
+    /// contain spaces, which rules out times.
+    pub fn union<V: Into<QueryValue>>(
+        self,
+        key: &str,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.term(key, format!("{{{}}}", join(values)))
+    }
+
+    /// Match resources where `key` has all of the `values` (`k=(v1 v2 v3)`), e.g. all of the
+    /// given labels. Values must not contain spaces, which rules out times.
+    pub fn intersection<V: Into<QueryValue>>(
+        self,
+        key: &str,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.term(key, format!("({})", join(values)))
+    }
+
+    fn term(mut self, key: &str, pattern: String) -> Self {
+        self.terms.push(format!("{key}={pattern}"));
+        self
+    }
+}
+
+fn join<V: Into<QueryValue>>(values: impl IntoIterator<Item = V>) -> String {
+    values
+        .into_iter()
+        .map(|value| value.into().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A range that includes its bounds, like Harbor's `[min~max]`: `a..=b`, `a..` or `..=b`.
+pub trait InclusiveRange<V> {
+    /// The lower and upper bound of the range, if any.
+    fn bounds(self) -> (Option<V>, Option<V>);
+}
+
+impl<V> InclusiveRange<V> for RangeInclusive<V> {
+    fn bounds(self) -> (Option<V>, Option<V>) {
+        let (start, end) = self.into_inner();
+        (Some(start), Some(end))
+    }
+}
+
+impl<V> InclusiveRange<V> for RangeFrom<V> {
+    fn bounds(self) -> (Option<V>, Option<V>) {
+        (Some(self.start), None)
+    }
+}
+
+impl<V> InclusiveRange<V> for RangeToInclusive<V> {
+    fn bounds(self) -> (Option<V>, Option<V>) {
+        (None, Some(self.end))
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.terms.join(","))
+    }
+}
+
+impl From<Query> for String {
+    fn from(query: Query) -> Self {
+        query.to_string()
+    }
+}
+
+/// A value to query resources by: a string, an integer or a time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QueryValue {
+    /// Quoted, so that Harbor never takes it for a number or a time. The quotes do not protect
+    /// commas, nor spaces in lists, see [`Query`].
+    String(String),
+    Integer(i64),
+    /// Formatted like `2020-04-09 02:36:00`, in UTC.
+    Time(DateTime<Utc>),
+}
+
+impl fmt::Display for QueryValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // Harbor has no escaping, so quote with whichever quote the value does not contain
+            QueryValue::String(value) if value.contains('"') => write!(f, "'{value}'"),
+            QueryValue::String(value) => write!(f, "\"{value}\""),
+            QueryValue::Integer(value) => write!(f, "{value}"),
+            QueryValue::Time(value) => write!(f, "{}", value.format(TIME_FORMAT)),
+        }
+    }
+}
+
+impl From<&str> for QueryValue {
+    fn from(value: &str) -> Self {
+        QueryValue::String(value.to_string())
+    }
+}
+
+impl From<String> for QueryValue {
+    fn from(value: String) -> Self {
+        QueryValue::String(value)
+    }
+}
+
+impl From<&String> for QueryValue {
+    fn from(value: &String) -> Self {
+        QueryValue::String(value.clone())
+    }
+}
+
+impl From<i32> for QueryValue {
+    fn from(value: i32) -> Self {
+        QueryValue::Integer(value.into())
+    }
+}
+
+impl From<i64> for QueryValue {
+    fn from(value: i64) -> Self {
+        QueryValue::Integer(value)
+    }
+}
+
+impl From<u32> for QueryValue {
+    fn from(value: u32) -> Self {
+        QueryValue::Integer(value.into())
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for QueryValue {
+    fn from(value: DateTime<Tz>) -> Self {
+        QueryValue::Time(value.with_timezone(&Utc))
+    }
+}
+
+impl From<NaiveDateTime> for QueryValue {
+    fn from(value: NaiveDateTime) -> Self {
+        QueryValue::Time(value.and_utc())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+
+use crate::request::{
+    HarborRequest,
+    query::Query,
+    v2::project::get::{GetProjectArtifacts, GetProjects},
+};
+
+#[test]
+fn exact_and_fuzzy_matches_are_quoted() {
+    let query = Query::new().eq("name", "foo").fuzzy("tags", "v1");
+    assert_eq!(r#"name="foo",tags=~"v1""#, query.to_string());
+}
+
+#[test]
+fn integers_are_not_quoted() {
+    let query = Query::new().eq("project_id", 12).range("size", 100..=2048);
+    assert_eq!("project_id=12,size=[100~2048]", query.to_string());
+}
+
+#[test]
+fn open_ranges_leave_a_side_empty() {
+    let query = Query::new().range("size", 100..).range("id", ..=10);
+    assert_eq!("size=[100~],id=[~10]", query.to_string());
+}
+
+#[test]
+fn ranges_include_both_bounds() {
+    let query = Query::new().range("size", 1..=10);
+    assert_eq!("size=[1~10]", query.to_string());
+}
+
+#[test]
+fn times_are_formatted_in_utc() {
+    let start = NaiveDate::from_ymd_opt(2020, 4, 9)
+        .unwrap()
+        .and_hms_opt(2, 36, 0)
+        .unwrap();
+    let end = chrono::FixedOffset::east_opt(2 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2020, 4, 10, 4, 36, 0)
+        .unwrap();
+    let query = Query::new().range("push_time", start.and_utc()..=end.with_timezone(&Utc));
+    assert_eq!(
+        "push_time=[2020-04-09 02:36:00~2020-04-10 02:36:00]",
+        query.to_string()
+    );
+}
+
+#[test]
+fn union_and_intersection_are_space_separated() {
+    let query = Query::new()
+        .union("media_type", ["image", "chart"])
+        .intersection("labels", [1, 2]);
+    assert_eq!(
+        r#"media_type={"image" "chart"},labels=(1 2)"#,
+        query.to_string()
+    );
+}
+
+#[test]
+fn values_with_double_quotes_are_single_quoted() {
+    let query = Query::new().eq("description", r#"say "hi""#);
+    assert_eq!(r#"description='say "hi"'"#, query.to_string());
+}
+
+#[test]
+fn commas_in_values_are_not_escaped() {
+    // Harbor splits this into the terms `name="a` and `b"`; there is no way to escape the comma
+    let query = Query::new().eq("name", "a,b");
+    assert_eq!(r#"name="a,b""#, query.to_string());
+}
+
+#[test]
+fn query_is_url_encoded_into_request() {
+    let request = GetProjects::builder()
+        .q(Query::new().eq("name", "foo"))
+        .build()
+        .unwrap();
+    assert_eq!("projects?q=name%3D%22foo%22", request.to_url());
+}
+
+#[test]
+fn query_can_be_set_on_artifact_requests() {
+    let request = GetProjectArtifacts::builder("library")
+        .q(Query::new().fuzzy("tags", "v1"))
+        .build()
+        .unwrap();
+    assert_eq!(Some(r#"tags=~"v1""#.to_string()), request.q);
+}
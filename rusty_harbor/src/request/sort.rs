@@ -0,0 +1,212 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+/// A field a list of resources can be sorted by.
+pub trait SortField: Copy {
+    /// The name of the field in the `sort` query parameter.
+    fn name(self) -> &'static str;
+}
+
+/// The `sort` query parameter of list requests, ordering by one or more fields of type `F`.
+///
+/// ```
+/// use rusty_harbor::request::sort::ArtifactSortField;
+///
+/// let sort = ArtifactSortField::PushTime
+///     .desc()
+///     .then_asc(ArtifactSortField::Digest);
+///
+/// assert_eq!("-push_time,digest", sort.to_string());
+/// ```
+///
+/// Sorting by a single field in ascending order only needs the field, since it converts into a
+/// `Sort`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sort<F> {
+    keys: Vec<(F, Order)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Order {
+    Ascending,
+    Descending,
+}
+
+impl<F: SortField> Sort<F> {
+    /// Sort by `field` in ascending order.
+    pub fn asc(field: F) -> Self {
+        Sort {
+            keys: vec![(field, Order::Ascending)],
+        }
+    }
+
+    /// Sort by `field` in descending order.
+    pub fn desc(field: F) -> Self {
+        Sort {
+            keys: vec![(field, Order::Descending)],
+        }
+    }
+
+    /// Then sort resources with equal values by `field` in ascending order.
+    pub fn then_asc(mut self, field: F) -> Self {
+        self.keys.push((field, Order::Ascending));
+        self
+    }
+
+    /// Then sort resources with equal values by `field` in descending order.
+    pub fn then_desc(mut self, field: F) -> Self {
+        self.keys.push((field, Order::Descending));
+        self
+    }
+}
+
+impl<F: SortField> From<F> for Sort<F> {
+    fn from(field: F) -> Self {
+        Sort::asc(field)
+    }
+}
+
+impl<F: SortField> fmt::Display for Sort<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (field, order)) in self.keys.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            if *order == Order::Descending {
+                f.write_str("-")?;
+            }
+            f.write_str(field.name())?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: SortField> Serialize for Sort<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Define an enum of the fields a resource can be sorted by.
+macro_rules! sort_fields {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $field:literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            /// Sort by this field in ascending order.
+            pub fn asc(self) -> Sort<Self> {
+                Sort::asc(self)
+            }
+
+            /// Sort by this field in descending order.
+            pub fn desc(self) -> Sort<Self> {
+                Sort::desc(self)
+            }
+        }
+
+        impl SortField for $name {
+            fn name(self) -> &'static str {
+                match self {
+                    $($name::$variant => $field),*
+                }
+            }
+        }
+    };
+}
+
+sort_fields! {
+    /// Fields to sort artifacts by.
+    ArtifactSortField {
+        Id => "id",
+        Type => "type",
+        MediaType => "media_type",
+        ManifestMediaType => "manifest_media_type",
+        Digest => "digest",
+        Size => "size",
+        PushTime => "push_time",
+        PullTime => "pull_time",
+    }
+}
+
+sort_fields! {
+    /// Fields to sort labels by.
+    LabelSortField {
+        Id => "id",
+        Name => "name",
+        CreationTime => "creation_time",
+        UpdateTime => "update_time",
+    }
+}
+
+sort_fields! {
+    /// Fields to sort projects by.
+    ProjectSortField {
+        ProjectId => "project_id",
+        Name => "name",
+        OwnerId => "owner_id",
+        CreationTime => "creation_time",
+        UpdateTime => "update_time",
+    }
+}
+
+sort_fields! {
+    /// Fields to sort registries by.
+    RegistrySortField {
+        Id => "id",
+        Name => "name",
+        Url => "url",
+        CreationTime => "creation_time",
+        UpdateTime => "update_time",
+    }
+}
+
+sort_fields! {
+    /// Fields to sort repositories by.
+    RepositorySortField {
+        Id => "id",
+        Name => "name",
+        PullCount => "pull_count",
+        CreationTime => "creation_time",
+        UpdateTime => "update_time",
+    }
+}
+
+sort_fields! {
+    /// Fields to sort robot accounts by.
+    RobotSortField {
+        Id => "id",
+        Name => "name",
+        CreationTime => "creation_time",
+        UpdateTime => "update_time",
+    }
+}
+
+sort_fields! {
+    /// Fields to sort the tags of an artifact by.
+    TagSortField {
+        Id => "id",
+        Name => "name",
+        PushTime => "push_time",
+        PullTime => "pull_time",
+    }
+}
+
+sort_fields! {
+    /// Fields to sort users by.
+    UserSortField {
+        UserId => "user_id",
+        Username => "username",
+        Email => "email",
+        Realname => "realname",
+        CreationTime => "creation_time",
+        UpdateTime => "update_time",
+    }
+}
+
+#[cfg(test)]
+mod tests;
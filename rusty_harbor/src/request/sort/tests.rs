@@ -0,0 +1,46 @@
+use crate::request::{
+    HarborRequest,
+    sort::{ArtifactSortField, ProjectSortField, RepositorySortField, Sort, TagSortField},
+    v2::{
+        artifact::get::GetArtifactTags, project::get::GetProjects, repository::get::GetRepositories,
+    },
+};
+
+#[test]
+fn descending_fields_are_prefixed_with_minus() {
+    assert_eq!("-push_time", ArtifactSortField::PushTime.desc().to_string());
+    assert_eq!("name", ProjectSortField::Name.asc().to_string());
+}
+
+#[test]
+fn multiple_fields_are_comma_separated() {
+    let sort = Sort::desc(RepositorySortField::PullCount).then_asc(RepositorySortField::Name);
+    assert_eq!("-pull_count,name", sort.to_string());
+}
+
+#[test]
+fn sort_is_added_to_the_query() {
+    let request = GetProjects::builder()
+        .sort(ProjectSortField::CreationTime.desc())
+        .build()
+        .unwrap();
+    assert_eq!("projects?sort=-creation_time", request.to_url());
+}
+
+#[test]
+fn single_field_sorts_ascending() {
+    let request = GetRepositories::builder()
+        .sort(RepositorySortField::Name)
+        .build()
+        .unwrap();
+    assert_eq!("repositories?sort=name", request.to_url());
+}
+
+#[test]
+fn tags_can_be_sorted() {
+    let request = GetArtifactTags::builder("library", "nginx", "latest")
+        .sort(TagSortField::PushTime.desc().then_desc(TagSortField::Id))
+        .build()
+        .unwrap();
+    assert!(request.to_url().ends_with("?sort=-push_time%2C-id"));
+}
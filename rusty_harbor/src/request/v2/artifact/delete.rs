@@ -0,0 +1,134 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// Delete the artifact specified by the reference under the project and repository. The
+/// reference can be digest or tag.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}",
+    method = "DELETE",
+    response = (),
+)]
+pub struct DeleteArtifact {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+}
+
+impl DeleteArtifact {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+    ) -> DeleteArtifactBuilder {
+        DeleteArtifactBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+    }
+}
+
+/// Delete the tag of the specified artifact.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}/tags/{tag_name}",
+    method = "DELETE",
+    response = (),
+)]
+pub struct DeleteArtifactTag {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+    /// The name of the tag.
+    #[serde(skip)]
+    pub tag_name: String,
+}
+
+impl DeleteArtifactTag {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+        tag_name: impl Into<String>,
+    ) -> DeleteArtifactTagBuilder {
+        DeleteArtifactTagBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+            .tag_name(tag_name)
+    }
+}
+
+/// Remove the label from the specified artiact.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}/labels/{label_id}",
+    method = "DELETE",
+    response = (),
+)]
+pub struct RemoveArtifactLabel {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+    /// The ID of the label to remove.
+    #[serde(skip)]
+    pub label_id: i64,
+}
+
+impl RemoveArtifactLabel {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+        label_id: i64,
+    ) -> RemoveArtifactLabelBuilder {
+        RemoveArtifactLabelBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+            .label_id(label_id)
+    }
+}
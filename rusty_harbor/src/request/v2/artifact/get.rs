@@ -0,0 +1,307 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{
+        HarborRequest, Paginated,
+        sort::{ArtifactSortField, Sort, TagSortField},
+    },
+    response::v2::{
+        project::{Artifact, types::Tag},
+        scan::VulnerabilityReports,
+    },
+};
+
+/// List artifacts under the specific project and repository. Except the basic properties, the
+/// other supported queries in "q" includes "tags=*" to list only tagged artifacts, "tags=nil" to
+/// list only untagged artifacts, "tags=~v" to list artifacts whose tag fuzzy matches "v",
+/// "tags=v" to list artifact whose tag exactly matches "v", "labels=(id1, id2)" to list artifacts
+/// that both labels with id1 and id2 are added to.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts",
+    method = "GET",
+    response = Vec<Artifact>,
+)]
+pub struct GetArtifacts {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// A comma-separated lists of MIME types for the scan report or scan summary. The first mime
+    /// type will be used when the report found for it.
+    ///
+    /// Currently the mime type supports 'application/vnd.scanner.adapter.vuln.report.harbor+json;
+    /// version=1.0' and 'application/vnd.security.vulnerability.report; version=1.1'
+    ///
+    /// Default value: application/vnd.security.vulnerability.report; version=1.1,
+    /// application/vnd.scanner.adapter.vuln.report.harbor+json; version=1.0
+    #[builder(default)]
+    #[header(rename = "X-Accept-Vulnerabilities")]
+    pub accept_vulnerabilities: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// Query string to query resources. Supported query patterns are "exact match(k=v)",
+    /// "fuzzy match(k=~v)", "range(k=[min~max])", "list with union releationship(k={v1 v2 v3})"
+    /// and "list with intersetion relationship(k=(v1 v2 v3))". The value of range and list can be
+    /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
+    /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
+    /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query).
+    #[builder(default)]
+    pub q: Option<String>,
+    /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
+    /// order and field2 in descending order with "sort=field1,-field2".
+    #[builder(default)]
+    pub sort: Option<Sort<ArtifactSortField>>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+    /// Specify whether the tags are included inside the returning artifacts.
+    /// Default value: `true`
+    #[builder(default)]
+    pub with_tag: Option<bool>,
+    /// Specify whether the labels are included inside the returning artifacts.
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_label: Option<bool>,
+    /// Specify whether the scan overview is included inside the returning artifacts.
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_scan_overview: Option<bool>,
+    /// Specify whether the SBOM overview is included in returning artifacts, when this option is
+    /// true, the SBOM overview will be included in the response.
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_sbom_overview: Option<bool>,
+    /// Specify whether the immutable status is included inside the tags of the returning artifacts.
+    /// Only works when setting "with_immutable_status=true".
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_immutable_status: Option<bool>,
+    /// Specify whether the accessories are included of the returning artifacts. Only works when
+    /// setting "with_accessory=true".
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_accessory: Option<bool>,
+}
+
+impl GetArtifacts {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+    ) -> GetArtifactsBuilder {
+        GetArtifactsBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+    }
+}
+
+/// Get the artifact specified by the reference under the project and repository. The reference
+/// can be digest or tag.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}",
+    method = "GET",
+    response = Artifact,
+)]
+pub struct GetArtifact {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// A comma-separated lists of MIME types for the scan report or scan summary. The first mime
+    /// type will be used when the report found for it.
+    ///
+    /// Currently the mime type supports 'application/vnd.scanner.adapter.vuln.report.harbor+json;
+    /// version=1.0' and 'application/vnd.security.vulnerability.report; version=1.1'
+    ///
+    /// Default value: application/vnd.security.vulnerability.report; version=1.1,
+    /// application/vnd.scanner.adapter.vuln.report.harbor+json; version=1.0
+    #[builder(default)]
+    #[header(rename = "X-Accept-Vulnerabilities")]
+    pub accept_vulnerabilities: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+    /// Specify whether the tags are included inside the returning artifacts.
+    /// Default value: `true`
+    #[builder(default)]
+    pub with_tag: Option<bool>,
+    /// Specify whether the labels are included inside the returning artifacts.
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_label: Option<bool>,
+    /// Specify whether the scan overview is included inside the returning artifacts.
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_scan_overview: Option<bool>,
+    /// Specify whether the SBOM overview is included in returning artifacts, when this option is
+    /// true, the SBOM overview will be included in the response.
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_sbom_overview: Option<bool>,
+    /// Specify whether the immutable status is included inside the tags of the returning artifacts.
+    /// Only works when setting "with_immutable_status=true".
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_immutable_status: Option<bool>,
+    /// Specify whether the accessories are included of the returning artifacts. Only works when
+    /// setting "with_accessory=true".
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_accessory: Option<bool>,
+}
+
+impl GetArtifact {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+    ) -> GetArtifactBuilder {
+        GetArtifactBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+    }
+}
+
+/// List tags of the specific artifact.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}/tags",
+    method = "GET",
+    response = Vec<Tag>,
+)]
+pub struct GetArtifactTags {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+    /// Query string to query resources. Supported query patterns are "exact match(k=v)",
+    /// "fuzzy match(k=~v)", "range(k=[min~max])", "list with union releationship(k={v1 v2 v3})"
+    /// and "list with intersetion relationship(k=(v1 v2 v3))". The value of range and list can be
+    /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
+    /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
+    /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query).
+    #[builder(default)]
+    pub q: Option<String>,
+    /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
+    /// order and field2 in descending order with "sort=field1,-field2".
+    #[builder(default)]
+    pub sort: Option<Sort<TagSortField>>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+    /// Specify whether the immutable status is inclued inside the returning tags.
+    /// Default value: `false`
+    #[builder(default)]
+    pub with_immutable_status: Option<bool>,
+}
+
+impl GetArtifactTags {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+    ) -> GetArtifactTagsBuilder {
+        GetArtifactTagsBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+    }
+}
+
+/// Get the vulnerabilities addition of the artifact specified by the reference under the project
+/// and repository.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}/additions/vulnerabilities",
+    method = "GET",
+    response = VulnerabilityReports,
+)]
+pub struct GetVulnerabilitiesAddition {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// A comma-separated lists of MIME types for the scan report or scan summary. The first mime
+    /// type will be used when the report found for it.
+    ///
+    /// Currently the mime type supports 'application/vnd.scanner.adapter.vuln.report.harbor+json;
+    /// version=1.0' and 'application/vnd.security.vulnerability.report; version=1.1'
+    ///
+    /// Default value: application/vnd.security.vulnerability.report; version=1.1,
+    /// application/vnd.scanner.adapter.vuln.report.harbor+json; version=1.0
+    #[builder(default)]
+    #[header(rename = "X-Accept-Vulnerabilities")]
+    pub accept_vulnerabilities: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+}
+
+impl GetVulnerabilitiesAddition {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+    ) -> GetVulnerabilitiesAdditionBuilder {
+        GetVulnerabilitiesAdditionBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+    }
+}
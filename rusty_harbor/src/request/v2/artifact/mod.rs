@@ -0,0 +1,5 @@
+pub mod delete;
+pub mod get;
+pub mod post;
+#[cfg(test)]
+mod tests;
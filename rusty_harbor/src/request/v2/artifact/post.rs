@@ -0,0 +1,133 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{request::HarborRequest, response::Created};
+
+/// Copy the artifact specified in the "from" parameter to the repository.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts",
+    method = "POST",
+    response = Created,
+)]
+pub struct CopyArtifact {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The artifact from which the new artifact is copied from, the format should be
+    /// "project/repository:tag" or "project/repository@digest".
+    pub from: String,
+}
+
+impl CopyArtifact {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        from: impl Into<String>,
+    ) -> CopyArtifactBuilder {
+        CopyArtifactBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .from(from)
+    }
+}
+
+/// Create a tag for the specified artifact.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}/tags",
+    method = "POST",
+    response = Created,
+)]
+pub struct CreateArtifactTag {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+    /// The name of the tag.
+    #[body(rename = "name")]
+    pub tag_name: String,
+}
+
+impl CreateArtifactTag {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+        tag_name: impl Into<String>,
+    ) -> CreateArtifactTagBuilder {
+        CreateArtifactTagBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+            .tag_name(tag_name)
+    }
+}
+
+/// Add label to the specified artiact.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}/labels",
+    method = "POST",
+    response = (),
+)]
+pub struct AddArtifactLabel {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+    /// The ID of the label to add.
+    #[body(rename = "id")]
+    pub label_id: i64,
+}
+
+impl AddArtifactLabel {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+        label_id: i64,
+    ) -> AddArtifactLabelBuilder {
+        AddArtifactLabelBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+            .label_id(label_id)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::request::{
+    HarborRequest,
+    v2::artifact::{
+        delete::{DeleteArtifact, DeleteArtifactTag, RemoveArtifactLabel},
+        get::{GetArtifact, GetArtifactTags, GetArtifacts},
+        post::{AddArtifactLabel, CopyArtifact, CreateArtifactTag},
+    },
+};
+
+const PROJECT_NAME: &str = "some-project-name";
+const REPOSITORY_NAME: &str = "some/repository";
+const ENCODED_REPOSITORY_NAME: &str = "some%252Frepository";
+const DIGEST: &str = "sha256:2b3f5b3b8c7e0f8f1b0e5f0f3c0a8f0d1e2d3c4b5a697887766554433221100f";
+
+#[test]
+fn get_artifacts_request_with_builder() {
+    let request = GetArtifacts::builder(PROJECT_NAME, REPOSITORY_NAME)
+        .with_tag(false)
+        .page_size(20)
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts?page_size=20&with_tag=false"
+    );
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn get_artifact_by_digest_request_with_builder() {
+    let request = GetArtifact::builder(PROJECT_NAME, REPOSITORY_NAME, DIGEST)
+        .with_scan_overview(true)
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{DIGEST}?with_scan_overview=true"
+    );
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn get_artifact_by_tag_request_with_builder() {
+    let request = GetArtifact::builder(PROJECT_NAME, REPOSITORY_NAME, "v1.0.0")
+        .build()
+        .unwrap();
+    let expected_url_encoded =
+        format!("projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/v1.0.0");
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn delete_artifact_request_with_builder() {
+    let request = DeleteArtifact::builder(PROJECT_NAME, REPOSITORY_NAME, DIGEST)
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{DIGEST}"
+    );
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn copy_artifact_request_with_builder() {
+    let request = CopyArtifact::builder(PROJECT_NAME, REPOSITORY_NAME, "other/some/repository:v1")
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts?from=other%2Fsome%2Frepository%3Av1"
+    );
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn get_artifact_tags_request_with_builder() {
+    let request = GetArtifactTags::builder(PROJECT_NAME, REPOSITORY_NAME, DIGEST)
+        .page_size(100)
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{DIGEST}/tags?page_size=100"
+    );
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn create_artifact_tag_request_with_builder() {
+    let request = CreateArtifactTag::builder(PROJECT_NAME, REPOSITORY_NAME, DIGEST, "stable")
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{DIGEST}/tags"
+    );
+    assert_eq!(expected_url_encoded, request.to_url());
+    assert_eq!(
+        Some(r#"{"name":"stable"}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn delete_artifact_tag_request_with_builder() {
+    let request = DeleteArtifactTag::builder(PROJECT_NAME, REPOSITORY_NAME, DIGEST, "stable")
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{DIGEST}/tags/stable"
+    );
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn add_artifact_label_request_with_builder() {
+    let request = AddArtifactLabel::builder(PROJECT_NAME, REPOSITORY_NAME, DIGEST, 7)
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{DIGEST}/labels"
+    );
+    assert_eq!(expected_url_encoded, request.to_url());
+    assert_eq!(Some(r#"{"id":7}"#.to_string()), request.body().unwrap());
+}
+
+#[test]
+fn remove_artifact_label_request_with_builder() {
+    let request = RemoveArtifactLabel::builder(PROJECT_NAME, REPOSITORY_NAME, DIGEST, 7)
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{DIGEST}/labels/7"
+    );
+    assert_eq!(expected_url_encoded, request.to_url())
+}
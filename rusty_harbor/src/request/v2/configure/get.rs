@@ -9,6 +9,7 @@ use crate::{request::HarborRequest, response::v2::configure::InternalConfigurati
 #[derive(Builder, Harbor, Serialize)]
 #[harbor(
     url = "internalconfig",
+    method = "GET",
     response = InternalConfigurationsResponse,
 )]
 pub struct GetConfiguration {
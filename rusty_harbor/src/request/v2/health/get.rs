@@ -9,6 +9,8 @@ use crate::{request::HarborRequest, response::v2::health::OverallHealthStatus};
 #[derive(Builder, Harbor, Serialize)]
 #[harbor(
     url = "health",
+    method = "GET",
+    anonymous,
     response = OverallHealthStatus,
 )]
 pub struct GetHealth {
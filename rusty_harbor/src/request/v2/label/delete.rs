@@ -0,0 +1,30 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// Delete the label specified by ID.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "labels/{label_id}",
+    method = "DELETE",
+    response = (),
+)]
+pub struct DeleteLabel {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Label ID
+    #[serde(skip)]
+    pub label_id: i64,
+}
+
+impl DeleteLabel {
+    pub fn builder(label_id: i64) -> DeleteLabelBuilder {
+        DeleteLabelBuilder::default().label_id(label_id)
+    }
+}
@@ -0,0 +1,97 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{
+        HarborRequest, Paginated,
+        sort::{LabelSortField, Sort},
+    },
+    response::v2::project::types::{Label, LabelScope},
+};
+
+/// This endpoint let user get the label by specific ID.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "labels/{label_id}",
+    method = "GET",
+    response = Label,
+)]
+pub struct GetLabel {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Label ID
+    #[serde(skip)]
+    pub label_id: i64,
+}
+
+impl GetLabel {
+    pub fn builder(label_id: i64) -> GetLabelBuilder {
+        GetLabelBuilder::default().label_id(label_id)
+    }
+}
+
+/// This endpoint let user list labels by name, scope and project_id.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "labels",
+    method = "GET",
+    response = Vec<Label>,
+)]
+pub struct GetLabels {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Query string to query resources. Supported query patterns are "exact match(k=v)",
+    /// "fuzzy match(k=~v)", "range(k=[min~max])", "list with union releationship(k={v1 v2 v3})"
+    /// and "list with intersetion relationship(k=(v1 v2 v3))". The value of range and list can be
+    /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
+    /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
+    /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query).
+    #[builder(default)]
+    pub q: Option<String>,
+    /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
+    /// order and field2 in descending order with "sort=field1,-field2".
+    #[builder(default)]
+    pub sort: Option<Sort<LabelSortField>>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+    /// The label name.
+    #[builder(default)]
+    pub name: Option<String>,
+    /// The label scope.
+    pub scope: LabelScope,
+    /// Relevant project ID, required when scope is p.
+    #[builder(default)]
+    pub project_id: Option<i64>,
+}
+
+impl GetLabels {
+    pub fn builder(scope: LabelScope) -> GetLabelsBuilder {
+        GetLabelsBuilder::default().scope(scope)
+    }
+
+    /// List the global labels, which can be added to artifacts of every project.
+    pub fn global() -> GetLabelsBuilder {
+        GetLabels::builder(LabelScope::Global)
+    }
+
+    /// List the labels of the project with the given ID.
+    pub fn project(project_id: i64) -> GetLabelsBuilder {
+        GetLabels::builder(LabelScope::Project).project_id(project_id)
+    }
+}
@@ -0,0 +1,33 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{HarborRequest, v2::label::types::LabelReq},
+    response::Created,
+};
+
+/// This endpoint let user creates a label.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "labels",
+    method = "POST",
+    response = Created,
+)]
+pub struct CreateLabel {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The json object of label.
+    #[body]
+    pub label: LabelReq,
+}
+
+impl CreateLabel {
+    pub fn builder(label: LabelReq) -> CreateLabelBuilder {
+        CreateLabelBuilder::default().label(label)
+    }
+}
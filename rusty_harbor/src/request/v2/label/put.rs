@@ -0,0 +1,35 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::{HarborRequest, v2::label::types::LabelReq};
+
+/// This endpoint let user update label properties.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "labels/{label_id}",
+    method = "PUT",
+    response = (),
+)]
+pub struct UpdateLabel {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Label ID
+    #[serde(skip)]
+    pub label_id: i64,
+    /// The label that replaces the current one, including the fields that do not change.
+    #[body]
+    pub label: LabelReq,
+}
+
+impl UpdateLabel {
+    pub fn builder(label_id: i64, label: LabelReq) -> UpdateLabelBuilder {
+        UpdateLabelBuilder::default()
+            .label_id(label_id)
+            .label(label)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    request::{
+        HarborRequest, Paginated,
+        sort::LabelSortField,
+        v2::label::{
+            delete::DeleteLabel,
+            get::{GetLabel, GetLabels},
+            post::CreateLabel,
+            put::UpdateLabel,
+            types::LabelReq,
+        },
+    },
+    response::v2::project::types::LabelScope,
+};
+
+const LABEL_ID: i64 = 42;
+
+#[test]
+fn get_label_request_with_builder() {
+    let request = GetLabel::builder(LABEL_ID).build().unwrap();
+    assert_eq!("labels/42", request.to_url())
+}
+
+#[test]
+fn get_global_labels_request() {
+    let request = GetLabels::global()
+        .name("qa-approved")
+        .sort(LabelSortField::Name)
+        .build()
+        .unwrap();
+    assert_eq!(
+        "labels?sort=name&name=qa-approved&scope=g",
+        request.to_url()
+    )
+}
+
+#[test]
+fn get_project_labels_request() {
+    let mut request = GetLabels::project(3).page(2).build().unwrap();
+    assert_eq!("labels?page=2&scope=p&project_id=3", request.to_url());
+
+    request.set_page(3);
+    assert_eq!("labels?page=3&scope=p&project_id=3", request.to_url())
+}
+
+#[test]
+fn create_label_request_with_builder() {
+    let label = LabelReq::builder("qa-approved")
+        .color("#48960C")
+        .scope(LabelScope::Project)
+        .project_id(3)
+        .build()
+        .unwrap();
+    let request = CreateLabel::builder(label).build().unwrap();
+    assert_eq!("labels", request.to_url());
+    assert_eq!(
+        Some(
+            r##"{"name":"qa-approved","color":"#48960C","scope":"p","project_id":3}"##.to_string()
+        ),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn update_label_request_with_builder() {
+    let label = LabelReq::builder("qa-approved")
+        .description("Passed the QA test suite")
+        .color("#48960C")
+        .build()
+        .unwrap();
+    let request = UpdateLabel::builder(LABEL_ID, label).build().unwrap();
+    assert_eq!("labels/42", request.to_url());
+    assert_eq!(
+        Some(
+            r##"{"name":"qa-approved","description":"Passed the QA test suite","color":"#48960C"}"##
+                .to_string()
+        ),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn delete_label_request_with_builder() {
+    let request = DeleteLabel::builder(LABEL_ID).build().unwrap();
+    assert_eq!("labels/42", request.to_url())
+}
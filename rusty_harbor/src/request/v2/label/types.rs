@@ -0,0 +1,35 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::response::v2::project::types::LabelScope;
+
+/// The JSON object of a label, used to create or update it. An update replaces the whole label,
+/// so it must carry every field the label should keep, not only the changed ones.
+#[derive(Builder, Debug, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+pub struct LabelReq {
+    /// The name the label.
+    pub name: String,
+    /// The description the label.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The color the label, e.g. "#61717D".
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The scope the label.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<LabelScope>,
+    /// The ID of project that the label belongs to, required for labels of project scope.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<i64>,
+}
+
+impl LabelReq {
+    pub fn builder(name: impl Into<String>) -> LabelReqBuilder {
+        LabelReqBuilder::default().name(name)
+    }
+}
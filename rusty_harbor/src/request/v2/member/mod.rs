@@ -0,0 +1,7 @@
+pub mod delete;
+pub mod get;
+pub mod post;
+pub mod put;
+#[cfg(test)]
+mod tests;
+pub mod types;
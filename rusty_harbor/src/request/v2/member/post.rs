@@ -0,0 +1,52 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{HarborRequest, v2::member::types::ProjectMemberReq},
+    response::Created,
+};
+
+/// Create project member relationship, the member can be one of the user_member and
+/// group_member. The user_member need to specify user_id or username. If the user already exist
+/// in harbor DB, specify the user_id, If does not exist in harbor DB, it will SearchAndOnBoard
+/// the user. The group_member need to specify id or ldap_group_dn. If the group already exist in
+/// harbor DB. specify the user group's id, If does not exist, it will SearchAndOnBoard the group.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name_or_id}/members",
+    method = "POST",
+    response = Created,
+)]
+pub struct CreateProjectMember {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The flag to indicate whether the parameter which supports both name and id in the path is
+    /// the name of the resource. When the X-Is-Resource-Name is false and the parameter can be
+    /// converted to an integer, the parameter will be as an id, otherwise, it will be as a name.
+    /// Default value: false
+    #[builder(default)]
+    #[header(rename = "X-Is-Resource-Name")]
+    pub is_resource_name: Option<bool>,
+    /// The name or id of the project.
+    #[serde(skip)]
+    pub project_name_or_id: String,
+    /// The member to add to the project.
+    #[body]
+    pub project_member: ProjectMemberReq,
+}
+
+impl CreateProjectMember {
+    pub fn builder(
+        project_name_or_id: impl Into<String>,
+        project_member: ProjectMemberReq,
+    ) -> CreateProjectMemberBuilder {
+        CreateProjectMemberBuilder::default()
+            .project_name_or_id(project_name_or_id)
+            .project_member(project_member)
+    }
+}
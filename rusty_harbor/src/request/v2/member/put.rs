@@ -0,0 +1,50 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{request::HarborRequest, response::v2::member::Role};
+
+/// Update project member relationship.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name_or_id}/members/{mid}",
+    method = "PUT",
+    response = (),
+)]
+pub struct UpdateProjectMember {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The flag to indicate whether the parameter which supports both name and id in the path is
+    /// the name of the resource. When the X-Is-Resource-Name is false and the parameter can be
+    /// converted to an integer, the parameter will be as an id, otherwise, it will be as a name.
+    /// Default value: false
+    #[builder(default)]
+    #[header(rename = "X-Is-Resource-Name")]
+    pub is_resource_name: Option<bool>,
+    /// The name or id of the project.
+    #[serde(skip)]
+    pub project_name_or_id: String,
+    /// The member ID.
+    #[serde(skip)]
+    pub mid: i64,
+    /// The new role of the member.
+    #[body(rename = "role_id")]
+    pub role: Role,
+}
+
+impl UpdateProjectMember {
+    pub fn builder(
+        project_name_or_id: impl Into<String>,
+        mid: i64,
+        role: Role,
+    ) -> UpdateProjectMemberBuilder {
+        UpdateProjectMemberBuilder::default()
+            .project_name_or_id(project_name_or_id)
+            .mid(mid)
+            .role(role)
+    }
+}
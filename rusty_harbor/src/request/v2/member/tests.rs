@@ -0,0 +1,93 @@
+use crate::{
+    request::{
+        HarborRequest,
+        v2::{
+            member::{
+                delete::DeleteProjectMember,
+                get::{GetProjectMember, GetProjectMembers},
+                post::CreateProjectMember,
+                put::UpdateProjectMember,
+                types::ProjectMemberReq,
+            },
+            usergroup::types::UserGroupReq,
+        },
+    },
+    response::v2::{member::Role, usergroup::GroupType},
+};
+
+const PROJECT_NAME: &str = "some-project-name";
+
+#[test]
+fn get_project_members_request_with_builder() {
+    let request = GetProjectMembers::builder(PROJECT_NAME)
+        .entityname("alice")
+        .page_size(50)
+        .build()
+        .unwrap();
+    let expected_url_encoded =
+        format!("projects/{PROJECT_NAME}/members?page_size=50&entityname=alice");
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn get_project_member_request_with_builder() {
+    let request = GetProjectMember::builder(PROJECT_NAME, 7).build().unwrap();
+    assert_eq!(
+        format!("projects/{PROJECT_NAME}/members/7"),
+        request.to_url()
+    )
+}
+
+#[test]
+fn create_project_user_member_request() {
+    let member = ProjectMemberReq::user(Role::Developer, "alice");
+    let request = CreateProjectMember::builder(PROJECT_NAME, member)
+        .build()
+        .unwrap();
+    assert_eq!(format!("projects/{PROJECT_NAME}/members"), request.to_url());
+    assert_eq!(
+        Some(r#"{"role_id":2,"member_user":{"username":"alice"}}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn create_project_group_member_request() {
+    let group = UserGroupReq::builder()
+        .group_type(GroupType::Ldap)
+        .ldap_group_dn("cn=qa,ou=groups,dc=example,dc=com")
+        .build()
+        .unwrap();
+    let member = ProjectMemberReq::group(Role::LimitedGuest, group);
+    let request = CreateProjectMember::builder(PROJECT_NAME, member)
+        .build()
+        .unwrap();
+    let expected_body = r#"{"role_id":5,"member_group":{"group_type":1,"ldap_group_dn":"cn=qa,ou=groups,dc=example,dc=com"}}"#;
+    assert_eq!(Some(expected_body.to_string()), request.body().unwrap());
+}
+
+#[test]
+fn update_project_member_request_with_builder() {
+    let request = UpdateProjectMember::builder(PROJECT_NAME, 7, Role::Maintainer)
+        .build()
+        .unwrap();
+    assert_eq!(
+        format!("projects/{PROJECT_NAME}/members/7"),
+        request.to_url()
+    );
+    assert_eq!(
+        Some(r#"{"role_id":4}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn delete_project_member_request_with_builder() {
+    let request = DeleteProjectMember::builder(PROJECT_NAME, 7)
+        .build()
+        .unwrap();
+    assert_eq!(
+        format!("projects/{PROJECT_NAME}/members/7"),
+        request.to_url()
+    )
+}
@@ -0,0 +1,65 @@
+use serde::Serialize;
+
+use crate::{request::v2::usergroup::types::UserGroupReq, response::v2::member::Role};
+
+/// The JSON object of a project member, used to add a user or a group to a project.
+#[derive(Debug, Serialize)]
+pub struct ProjectMemberReq {
+    /// The role of the member.
+    pub role_id: Role,
+    /// The user to add, if the member is a user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_user: Option<MemberUser>,
+    /// The group to add, if the member is a group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_group: Option<UserGroupReq>,
+}
+
+impl ProjectMemberReq {
+    /// Add the user with the given name. Users that are not in the Harbor database yet, e.g.
+    /// LDAP users, are onboarded.
+    pub fn user(role: Role, username: impl Into<String>) -> Self {
+        ProjectMemberReq {
+            role_id: role,
+            member_user: Some(MemberUser {
+                user_id: None,
+                username: Some(username.into()),
+            }),
+            member_group: None,
+        }
+    }
+
+    /// Add the user with the given ID.
+    pub fn user_id(role: Role, user_id: i64) -> Self {
+        ProjectMemberReq {
+            role_id: role,
+            member_user: Some(MemberUser {
+                user_id: Some(user_id),
+                username: None,
+            }),
+            member_group: None,
+        }
+    }
+
+    /// Add a user group. Groups that are not in the Harbor database yet, e.g. LDAP groups given
+    /// by their DN, are onboarded.
+    pub fn group(role: Role, group: UserGroupReq) -> Self {
+        ProjectMemberReq {
+            role_id: role,
+            member_user: None,
+            member_group: Some(group),
+        }
+    }
+}
+
+/// The user of a project member. The user_id is used if the user already exists in the Harbor
+/// database, otherwise the username is searched for and onboarded.
+#[derive(Debug, Serialize)]
+pub struct MemberUser {
+    /// The ID of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+    /// The name of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
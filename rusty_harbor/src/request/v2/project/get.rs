@@ -4,7 +4,10 @@ use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::Serialize;
 
 use crate::{
-    request::HarborRequest,
+    request::{
+        HarborRequest, Paginated,
+        sort::{ArtifactSortField, ProjectSortField, Sort},
+    },
     response::v2::project::{Artifact, Project, ProjectDeletable, ProjectSummary},
 };
 
@@ -13,6 +16,7 @@ use crate::{
 #[builder(setter(into, strip_option), pattern = "owned")]
 #[harbor(
     url = "projects/{project_name_or_id}",
+    method = "GET",
     response = Project,
 )]
 pub struct GetProject {
@@ -43,6 +47,7 @@ impl GetProject {
 #[builder(setter(into, strip_option), pattern = "owned")]
 #[harbor(
     url = "projects/{project_name_or_id}/_deletable",
+    method = "GET",
     response = ProjectDeletable,
 )]
 pub struct GetProjectDeletable {
@@ -73,6 +78,7 @@ impl GetProjectDeletable {
 #[builder(setter(into, strip_option), pattern = "owned")]
 #[harbor(
     url = "projects/{project_name_or_id}/summary",
+    method = "GET",
     response = ProjectSummary,
 )]
 pub struct GetProjectSummary {
@@ -103,6 +109,7 @@ impl GetProjectSummary {
 #[builder(setter(into, strip_option), pattern = "owned")]
 #[harbor(
     url = "projects/{project_name_or_id}/artifacts",
+    method = "GET",
     response = Vec<Artifact>,
 )]
 pub struct GetProjectArtifacts {
@@ -137,12 +144,14 @@ pub struct GetProjectArtifacts {
     /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
     /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
     /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query).
     #[builder(default)]
     pub q: Option<String>,
     /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
     /// order and field2 in descending order with "sort=field1,-field2".
     #[builder(default)]
-    pub sort: Option<String>,
+    pub sort: Option<Sort<ArtifactSortField>>,
     /// The page number.
     /// Default value: `1`
     #[builder(default)]
@@ -197,6 +206,7 @@ impl GetProjectArtifacts {
 #[builder(setter(into, strip_option), pattern = "owned")]
 #[harbor(
     url = "projects",
+    method = "GET",
     response = Vec<Project>,
 )]
 pub struct GetProjects {
@@ -210,6 +220,8 @@ pub struct GetProjects {
     /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
     /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
     /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query).
     #[builder(default)]
     pub q: Option<String>,
     /// The page number.
@@ -223,7 +235,7 @@ pub struct GetProjects {
     /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
     /// order and field2 in descending order with "sort=field1,-field2".
     #[builder(default)]
-    pub sort: Option<String>,
+    pub sort: Option<Sort<ProjectSortField>>,
     /// The name of project.
     #[builder(default)]
     pub name: Option<String>,
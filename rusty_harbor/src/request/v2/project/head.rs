@@ -10,6 +10,7 @@ use crate::request::HarborRequest;
 #[derive(Builder, Harbor, Serialize)]
 #[harbor(
     url = "projects",
+    method = "HEAD",
     response = Value,
 )]
 pub struct HeadProjects {
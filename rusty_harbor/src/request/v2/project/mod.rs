@@ -1,4 +1,8 @@
+pub mod delete;
 pub mod get;
 pub mod head;
+pub mod post;
+pub mod put;
 #[cfg(test)]
 mod tests;
+pub mod types;
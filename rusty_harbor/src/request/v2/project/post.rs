@@ -0,0 +1,51 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{HarborRequest, v2::project::types::ProjectMetadataReq},
+    response::Created,
+};
+
+/// This endpoint is for user to create a new project.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects",
+    method = "POST",
+    response = Created,
+)]
+pub struct CreateProject {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The flag to indicate whether to return the name of the resource in Location. When
+    /// X-Resource-Name-In-Location is true, the Location will return the name of the resource.
+    /// Default value: false
+    #[builder(default)]
+    #[header(rename = "X-Resource-Name-In-Location")]
+    pub resource_name_in_location: Option<bool>,
+    /// The name of the project.
+    #[body(field)]
+    pub project_name: String,
+    /// The metadata of the project.
+    #[builder(default)]
+    #[body(field)]
+    pub metadata: Option<ProjectMetadataReq>,
+    /// The storage quota of the project, in bytes. `-1` means unlimited.
+    #[builder(default)]
+    #[body(field)]
+    pub storage_limit: Option<i64>,
+    /// The ID of referenced registry when creating the proxy cache project.
+    #[builder(default)]
+    #[body(field)]
+    pub registry_id: Option<i64>,
+}
+
+impl CreateProject {
+    pub fn builder(project_name: impl Into<String>) -> CreateProjectBuilder {
+        CreateProjectBuilder::default().project_name(project_name)
+    }
+}
@@ -0,0 +1,45 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::{HarborRequest, v2::project::types::ProjectReq};
+
+/// This endpoint is aimed to update the properties of a project.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name_or_id}",
+    method = "PUT",
+    response = (),
+)]
+pub struct UpdateProject {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The flag to indicate whether the parameter which supports both name and id in the path is
+    /// the name of the resource. When the X-Is-Resource-Name is false and the parameter can be
+    /// converted to an integer, the parameter will be as an id, otherwise, it will be as a name.
+    /// Default value: false
+    #[builder(default)]
+    #[header(rename = "X-Is-Resource-Name")]
+    pub is_resource_name: Option<bool>,
+    /// The name or id of the project.
+    #[serde(skip)]
+    pub project_name_or_id: String,
+    /// Updates of the project.
+    #[body]
+    pub project: ProjectReq,
+}
+
+impl UpdateProject {
+    pub fn builder(
+        project_name_or_id: impl Into<String>,
+        project: ProjectReq,
+    ) -> UpdateProjectBuilder {
+        UpdateProjectBuilder::default()
+            .project_name_or_id(project_name_or_id)
+            .project(project)
+    }
+}
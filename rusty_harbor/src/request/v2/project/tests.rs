@@ -1,8 +1,18 @@
-use crate::request::{
-    HarborRequest,
-    v2::project::get::{
-        GetProject, GetProjectArtifacts, GetProjectDeletable, GetProjectSummary, GetProjects,
+use crate::{
+    request::{
+        HarborRequest, Paginated,
+        v2::project::{
+            delete::DeleteProject,
+            get::{
+                GetProject, GetProjectArtifacts, GetProjectDeletable, GetProjectSummary,
+                GetProjects,
+            },
+            post::CreateProject,
+            put::UpdateProject,
+            types::{ProjectMetadataReq, ProjectReq},
+        },
     },
+    response::v2::project::types::Severity,
 };
 
 const PROJECT_NAME: &str = "some-project-name";
@@ -85,3 +95,82 @@ fn get_projects_request_with_builder() {
     let expected_url_encoded = "projects?page=10&page_size=100&name=foobar&public=false";
     assert_eq!(expected_url_encoded, request.to_url())
 }
+
+#[test]
+fn get_projects_request_can_be_paginated() {
+    let mut request = GetProjects::builder().page_size(100).build().unwrap();
+    assert_eq!(None, request.page());
+    request.set_page(2);
+    assert_eq!(Some(2), request.page());
+    assert_eq!(Some(100), request.page_size());
+    assert_eq!("projects?page=2&page_size=100", request.to_url())
+}
+
+#[test]
+fn create_project_request_with_builder() {
+    let request = CreateProject::builder(PROJECT_NAME)
+        .storage_limit(1024)
+        .registry_id(3)
+        .build()
+        .unwrap();
+    assert_eq!("projects", request.to_url());
+    assert_eq!(
+        Some(format!(
+            r#"{{"project_name":"{PROJECT_NAME}","registry_id":3,"storage_limit":1024}}"#
+        )),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn update_project_request_with_builder() {
+    let project = ProjectReq::builder().storage_limit(-1).build().unwrap();
+    let request = UpdateProject::builder(PROJECT_NAME, project)
+        .is_resource_name(true)
+        .build()
+        .unwrap();
+    assert_eq!(format!("projects/{PROJECT_NAME}"), request.to_url());
+    assert_eq!(
+        Some(r#"{"storage_limit":-1}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn update_project_metadata_sends_only_the_set_fields() {
+    let metadata = ProjectMetadataReq::builder()
+        .public(true)
+        .severity(Severity::High)
+        .build()
+        .unwrap();
+    let project = ProjectReq::builder().metadata(metadata).build().unwrap();
+    let request = UpdateProject::builder(PROJECT_NAME, project)
+        .build()
+        .unwrap();
+    assert_eq!(
+        Some(r#"{"metadata":{"public":"true","severity":"high"}}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn update_project_auto_scan_leaves_public_out() {
+    let metadata = ProjectMetadataReq::builder()
+        .auto_scan(true)
+        .build()
+        .unwrap();
+    let project = ProjectReq::builder().metadata(metadata).build().unwrap();
+    let request = UpdateProject::builder(PROJECT_NAME, project)
+        .build()
+        .unwrap();
+    let body = request.body().unwrap().unwrap();
+    assert_eq!(r#"{"metadata":{"auto_scan":"true"}}"#, body);
+    assert!(!body.contains("public"));
+}
+
+#[test]
+fn delete_project_request_with_builder() {
+    let request = DeleteProject::builder(PROJECT_NAME).build().unwrap();
+    assert_eq!(format!("projects/{PROJECT_NAME}"), request.to_url());
+    assert_eq!(None, request.body().unwrap());
+}
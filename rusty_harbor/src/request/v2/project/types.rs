@@ -0,0 +1,110 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::response::v2::project::types::Severity;
+
+/// The JSON object of a project, used to update it. Only the fields that are set are sent.
+#[derive(Builder, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+pub struct ProjectReq {
+    /// The metadata of the project.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ProjectMetadataReq>,
+    /// The storage quota of the project, in bytes. `-1` means unlimited.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_limit: Option<i64>,
+    /// The ID of referenced registry when creating the proxy cache project.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_id: Option<i64>,
+}
+
+impl ProjectReq {
+    pub fn builder() -> ProjectReqBuilder {
+        ProjectReqBuilder::default()
+    }
+}
+
+/// The metadata of a project, used to create or update it. Only the fields that are set are
+/// sent, so an update leaves the others as they are.
+#[derive(Builder, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+pub struct ProjectMetadataReq {
+    /// The public status of the project.
+    #[builder(default)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::response::string_bool::option::serialize"
+    )]
+    pub public: Option<bool>,
+    /// Whether content trust is enabled or not. If it is enabled, user can't pull unsigned images
+    /// from this project.
+    #[builder(default)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::response::string_bool::option::serialize"
+    )]
+    pub enable_content_trust: Option<bool>,
+    /// Whether cosign content trust is enabled or not. If it is enabled, user can't pull images
+    /// without cosign signature from this project.
+    #[builder(default)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::response::string_bool::option::serialize"
+    )]
+    pub enable_content_trust_cosign: Option<bool>,
+    /// Whether prevent the vulnerable images from running.
+    #[builder(default)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::response::string_bool::option::serialize"
+    )]
+    pub prevent_vul: Option<bool>,
+    /// If the vulnerability is high than severity defined here, the images can't be pulled.
+    #[builder(default)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::response::v2::project::types::severity_lowercase::serialize"
+    )]
+    pub severity: Option<Severity>,
+    /// Whether scan images automatically when pushing.
+    #[builder(default)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::response::string_bool::option::serialize"
+    )]
+    pub auto_scan: Option<bool>,
+    /// Whether generating SBOM automatically when pushing a subject artifact.
+    #[builder(default)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::response::string_bool::option::serialize"
+    )]
+    pub auto_sbom_generation: Option<bool>,
+    /// Whether this project reuse the system level CVE allowlist as the allowlist of its own. If
+    /// it is set to `true` the actual allowlist associate with this project, if any, will be
+    /// ignored.
+    #[builder(default)]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::response::string_bool::option::serialize"
+    )]
+    pub reuse_sys_cve_allowlist: Option<bool>,
+    /// The ID of the tag retention policy for the project.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention_id: Option<String>,
+    /// The bandwidth limit of proxy cache, in Kbps (kilobits per second). It limits the
+    /// communication between Harbor and the upstream registry, not the client and the Harbor.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_speed_kb: Option<String>,
+}
+
+impl ProjectMetadataReq {
+    pub fn builder() -> ProjectMetadataReqBuilder {
+        ProjectMetadataReqBuilder::default()
+    }
+}
@@ -0,0 +1,30 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// Delete the specific registry.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "registries/{id}",
+    method = "DELETE",
+    response = (),
+)]
+pub struct DeleteRegistry {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Registry ID
+    #[serde(skip)]
+    pub id: i64,
+}
+
+impl DeleteRegistry {
+    pub fn builder(id: i64) -> DeleteRegistryBuilder {
+        DeleteRegistryBuilder::default().id(id)
+    }
+}
@@ -0,0 +1,125 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{
+        HarborRequest, Paginated,
+        sort::{RegistrySortField, Sort},
+    },
+    response::v2::registry::{Registry, RegistryInfo},
+};
+
+/// Get the specific registry.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "registries/{id}",
+    method = "GET",
+    response = Registry,
+)]
+pub struct GetRegistry {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Registry ID
+    #[serde(skip)]
+    pub id: i64,
+}
+
+impl GetRegistry {
+    pub fn builder(id: i64) -> GetRegistryBuilder {
+        GetRegistryBuilder::default().id(id)
+    }
+}
+
+/// List the registries.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "registries",
+    method = "GET",
+    response = Vec<Registry>,
+)]
+pub struct GetRegistries {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Query string to query resources. Supported query patterns are "exact match(k=v)",
+    /// "fuzzy match(k=~v)", "range(k=[min~max])", "list with union releationship(k={v1 v2 v3})"
+    /// and "list with intersetion relationship(k=(v1 v2 v3))". The value of range and list can be
+    /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
+    /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
+    /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query).
+    #[builder(default)]
+    pub q: Option<String>,
+    /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
+    /// order and field2 in descending order with "sort=field1,-field2".
+    #[builder(default)]
+    pub sort: Option<Sort<RegistrySortField>>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+}
+
+impl GetRegistries {
+    pub fn builder() -> GetRegistriesBuilder {
+        GetRegistriesBuilder::default()
+    }
+}
+
+/// Get the registry info.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "registries/{id}/info",
+    method = "GET",
+    response = RegistryInfo,
+)]
+pub struct GetRegistryInfo {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Registry ID
+    #[serde(skip)]
+    pub id: i64,
+}
+
+impl GetRegistryInfo {
+    pub fn builder(id: i64) -> GetRegistryInfoBuilder {
+        GetRegistryInfoBuilder::default().id(id)
+    }
+}
+
+/// List registry adapters, i.e. the types of registries Harbor can connect to, e.g. 'harbor',
+/// 'docker-hub' or 'github-ghcr'.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "replication/adapters",
+    method = "GET",
+    response = Vec<String>,
+)]
+pub struct GetRegistryAdapters {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+}
+
+impl GetRegistryAdapters {
+    pub fn builder() -> GetRegistryAdaptersBuilder {
+        GetRegistryAdaptersBuilder::default()
+    }
+}
@@ -0,0 +1,83 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{HarborRequest, v2::registry::types::RegistryReq},
+    response::Created,
+};
+
+/// Create a registry.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "registries",
+    method = "POST",
+    response = Created,
+)]
+pub struct CreateRegistry {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The registry.
+    #[body]
+    pub registry: RegistryReq,
+}
+
+impl CreateRegistry {
+    pub fn builder(registry: RegistryReq) -> CreateRegistryBuilder {
+        CreateRegistryBuilder::default().registry(registry)
+    }
+}
+
+/// Check status of a registry, either of an existing one by its ID, or of one yet to be created
+/// by its type, URL and credential.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "registries/ping",
+    method = "POST",
+    response = (),
+)]
+pub struct PingRegistry {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The registry ID.
+    #[builder(default)]
+    #[body(field)]
+    pub id: Option<i64>,
+    /// Type of the registry, e.g. 'harbor'.
+    #[builder(default)]
+    #[body(rename = "type")]
+    pub kind: Option<String>,
+    /// The registry URL.
+    #[builder(default)]
+    #[body(field)]
+    pub url: Option<String>,
+    /// Credential type of the registry, e.g. 'basic'.
+    #[builder(default)]
+    #[body(field)]
+    pub credential_type: Option<String>,
+    /// The registry access key.
+    #[builder(default)]
+    #[body(field)]
+    pub access_key: Option<String>,
+    /// The registry access secret.
+    #[builder(default)]
+    #[body(field)]
+    pub access_secret: Option<String>,
+    /// Whether or not the certificate will be verified when Harbor tries to access the server.
+    #[builder(default)]
+    #[body(field)]
+    pub insecure: Option<bool>,
+}
+
+impl PingRegistry {
+    pub fn builder() -> PingRegistryBuilder {
+        PingRegistryBuilder::default()
+    }
+}
@@ -0,0 +1,58 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// Update the registry.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "registries/{id}",
+    method = "PUT",
+    response = (),
+)]
+pub struct UpdateRegistry {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Registry ID
+    #[serde(skip)]
+    pub id: i64,
+    /// The registry name.
+    #[builder(default)]
+    #[body(field)]
+    pub name: Option<String>,
+    /// Description of the registry.
+    #[builder(default)]
+    #[body(field)]
+    pub description: Option<String>,
+    /// The registry URL.
+    #[builder(default)]
+    #[body(field)]
+    pub url: Option<String>,
+    /// Credential type of the registry, e.g. 'basic'.
+    #[builder(default)]
+    #[body(field)]
+    pub credential_type: Option<String>,
+    /// The registry access key.
+    #[builder(default)]
+    #[body(field)]
+    pub access_key: Option<String>,
+    /// The registry access secret.
+    #[builder(default)]
+    #[body(field)]
+    pub access_secret: Option<String>,
+    /// Whether or not the certificate will be verified when Harbor tries to access the server.
+    #[builder(default)]
+    #[body(field)]
+    pub insecure: Option<bool>,
+}
+
+impl UpdateRegistry {
+    pub fn builder(id: i64) -> UpdateRegistryBuilder {
+        UpdateRegistryBuilder::default().id(id)
+    }
+}
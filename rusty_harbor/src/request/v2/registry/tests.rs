@@ -0,0 +1,88 @@
+use crate::request::{
+    HarborRequest,
+    sort::RegistrySortField,
+    v2::registry::{
+        delete::DeleteRegistry,
+        get::{GetRegistries, GetRegistry, GetRegistryAdapters, GetRegistryInfo},
+        post::{CreateRegistry, PingRegistry},
+        put::UpdateRegistry,
+        types::{RegistryCredentialReq, RegistryReq},
+    },
+};
+
+const REGISTRY_ID: i64 = 2;
+
+#[test]
+fn get_registry_requests_with_builder() {
+    let request = GetRegistry::builder(REGISTRY_ID).build().unwrap();
+    assert_eq!("registries/2", request.to_url());
+
+    let request = GetRegistryInfo::builder(REGISTRY_ID).build().unwrap();
+    assert_eq!("registries/2/info", request.to_url());
+
+    let request = GetRegistryAdapters::builder().build().unwrap();
+    assert_eq!("replication/adapters", request.to_url())
+}
+
+#[test]
+fn get_registries_request_with_builder() {
+    let request = GetRegistries::builder()
+        .sort(RegistrySortField::Name)
+        .page_size(50)
+        .build()
+        .unwrap();
+    assert_eq!("registries?sort=name&page_size=50", request.to_url())
+}
+
+#[test]
+fn create_registry_request_with_builder() {
+    let registry = RegistryReq::builder("ghcr", "https://ghcr.io", "github-ghcr")
+        .credential(RegistryCredentialReq::basic("octocat", "ghp_token"))
+        .build()
+        .unwrap();
+    assert!(!format!("{registry:?}").contains("ghp_token"));
+
+    let request = CreateRegistry::builder(registry).build().unwrap();
+    assert_eq!("registries", request.to_url());
+    let expected_body = concat!(
+        r#"{"name":"ghcr","url":"https://ghcr.io","type":"github-ghcr","#,
+        r#""credential":{"type":"basic","access_key":"octocat","access_secret":"ghp_token"}}"#
+    );
+    assert_eq!(Some(expected_body.to_string()), request.body().unwrap());
+}
+
+#[test]
+fn ping_registry_request_with_builder() {
+    let request = PingRegistry::builder()
+        .kind("docker-hub")
+        .url("https://hub.docker.com")
+        .build()
+        .unwrap();
+    assert_eq!("registries/ping", request.to_url());
+    assert_eq!(
+        Some(r#"{"type":"docker-hub","url":"https://hub.docker.com"}"#.to_string()),
+        request.body().unwrap()
+    );
+
+    let request = PingRegistry::builder().id(REGISTRY_ID).build().unwrap();
+    assert_eq!(Some(r#"{"id":2}"#.to_string()), request.body().unwrap());
+}
+
+#[test]
+fn update_registry_request_with_builder() {
+    let request = UpdateRegistry::builder(REGISTRY_ID)
+        .access_secret("new-token")
+        .build()
+        .unwrap();
+    assert_eq!("registries/2", request.to_url());
+    assert_eq!(
+        Some(r#"{"access_secret":"new-token"}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn delete_registry_request_with_builder() {
+    let request = DeleteRegistry::builder(REGISTRY_ID).build().unwrap();
+    assert_eq!("registries/2", request.to_url())
+}
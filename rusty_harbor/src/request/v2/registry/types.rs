@@ -0,0 +1,74 @@
+use std::fmt;
+
+use derive_builder::Builder;
+use serde::Serialize;
+
+/// The JSON object of a registry, used to create it.
+#[derive(Builder, Debug, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+pub struct RegistryReq {
+    /// The registry name.
+    pub name: String,
+    /// The registry URL string.
+    pub url: String,
+    /// Type of the registry, e.g. 'docker-hub' or 'github-ghcr'. One of the
+    /// [registry adapters](crate::request::v2::registry::get::GetRegistryAdapters).
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Description of the registry.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether or not the certificate will be verified when Harbor tries to access the server.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure: Option<bool>,
+    /// The credential of the registry, if it is not accessed anonymously.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credential: Option<RegistryCredentialReq>,
+}
+
+impl RegistryReq {
+    pub fn builder(
+        name: impl Into<String>,
+        url: impl Into<String>,
+        kind: impl Into<String>,
+    ) -> RegistryReqBuilder {
+        RegistryReqBuilder::default().name(name).url(url).kind(kind)
+    }
+}
+
+/// The credential of a registry.
+#[derive(Serialize)]
+pub struct RegistryCredentialReq {
+    /// Credential type, such as 'basic', 'oauth'.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Access key, e.g. user name when credential type is 'basic'.
+    pub access_key: String,
+    /// Access secret, e.g. password when credential type is 'basic'.
+    pub access_secret: String,
+}
+
+impl RegistryCredentialReq {
+    /// Authenticate with a user name and a password (or an access token).
+    pub fn basic(access_key: impl Into<String>, access_secret: impl Into<String>) -> Self {
+        RegistryCredentialReq {
+            kind: "basic".to_string(),
+            access_key: access_key.into(),
+            access_secret: access_secret.into(),
+        }
+    }
+}
+
+/// Never print the access secret.
+impl fmt::Debug for RegistryCredentialReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegistryCredentialReq")
+            .field("kind", &self.kind)
+            .field("access_key", &self.access_key)
+            .field("access_secret", &"<redacted>")
+            .finish()
+    }
+}
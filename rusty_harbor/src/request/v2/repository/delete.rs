@@ -0,0 +1,40 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// Delete the repository specified by name.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}",
+    method = "DELETE",
+    response = (),
+)]
+pub struct DeleteRepository {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+}
+
+impl DeleteRepository {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+    ) -> DeleteRepositoryBuilder {
+        DeleteRepositoryBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+    }
+}
@@ -0,0 +1,135 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{
+        HarborRequest, Paginated,
+        sort::{RepositorySortField, Sort},
+    },
+    response::v2::repository::Repository,
+};
+
+/// List all authorized repositories.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "repositories",
+    method = "GET",
+    response = Vec<Repository>,
+)]
+pub struct GetRepositories {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Query string to query resources. Supported query patterns are "exact match(k=v)",
+    /// "fuzzy match(k=~v)", "range(k=[min~max])", "list with union releationship(k={v1 v2 v3})"
+    /// and "list with intersetion relationship(k=(v1 v2 v3))". The value of range and list can be
+    /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
+    /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
+    /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query).
+    #[builder(default)]
+    pub q: Option<String>,
+    /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
+    /// order and field2 in descending order with "sort=field1,-field2".
+    #[builder(default)]
+    pub sort: Option<Sort<RepositorySortField>>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+}
+
+impl GetRepositories {
+    pub fn builder() -> GetRepositoriesBuilder {
+        GetRepositoriesBuilder::default()
+    }
+}
+
+/// List repositories of the specified project.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories",
+    method = "GET",
+    response = Vec<Repository>,
+)]
+pub struct GetProjectRepositories {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// Query string to query resources. Supported query patterns are "exact match(k=v)",
+    /// "fuzzy match(k=~v)", "range(k=[min~max])", "list with union releationship(k={v1 v2 v3})"
+    /// and "list with intersetion relationship(k=(v1 v2 v3))". The value of range and list can be
+    /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
+    /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
+    /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query).
+    #[builder(default)]
+    pub q: Option<String>,
+    /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
+    /// order and field2 in descending order with "sort=field1,-field2".
+    #[builder(default)]
+    pub sort: Option<Sort<RepositorySortField>>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+}
+
+impl GetProjectRepositories {
+    pub fn builder(project_name: impl Into<String>) -> GetProjectRepositoriesBuilder {
+        GetProjectRepositoriesBuilder::default().project_name(project_name)
+    }
+}
+
+/// Get the repository specified by name.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}",
+    method = "GET",
+    response = Repository,
+)]
+pub struct GetRepository {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+}
+
+impl GetRepository {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+    ) -> GetRepositoryBuilder {
+        GetRepositoryBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+    }
+}
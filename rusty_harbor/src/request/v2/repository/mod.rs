@@ -0,0 +1,5 @@
+pub mod delete;
+pub mod get;
+pub mod put;
+#[cfg(test)]
+mod tests;
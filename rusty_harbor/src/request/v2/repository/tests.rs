@@ -0,0 +1,67 @@
+use crate::request::{
+    HarborRequest,
+    v2::repository::{
+        delete::DeleteRepository,
+        get::{GetProjectRepositories, GetRepositories, GetRepository},
+        put::UpdateRepository,
+    },
+};
+
+const PROJECT_NAME: &str = "some-project-name";
+const REPOSITORY_NAME: &str = "some/repository";
+const ENCODED_REPOSITORY_NAME: &str = "some%252Frepository";
+
+#[test]
+fn get_repositories_request_with_builder() {
+    let request = GetRepositories::builder()
+        .page(2)
+        .page_size(50)
+        .build()
+        .unwrap();
+    assert_eq!("repositories?page=2&page_size=50", request.to_url())
+}
+
+#[test]
+fn get_project_repositories_request_with_builder() {
+    let request = GetProjectRepositories::builder(PROJECT_NAME)
+        .q("name=~nginx")
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!("projects/{PROJECT_NAME}/repositories?q=name%3D%7Enginx");
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn get_repository_request_encodes_repository_name_twice() {
+    let request = GetRepository::builder(PROJECT_NAME, REPOSITORY_NAME)
+        .build()
+        .unwrap();
+    let expected_url_encoded =
+        format!("projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}");
+    assert_eq!(expected_url_encoded, request.to_url())
+}
+
+#[test]
+fn update_repository_request_has_body() {
+    let request = UpdateRepository::builder(PROJECT_NAME, REPOSITORY_NAME, "Some description")
+        .build()
+        .unwrap();
+    let expected_url_encoded =
+        format!("projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}");
+    assert_eq!(expected_url_encoded, request.to_url());
+    assert_eq!(
+        Some(r#"{"description":"Some description"}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn delete_repository_request_with_builder() {
+    let request = DeleteRepository::builder(PROJECT_NAME, REPOSITORY_NAME)
+        .build()
+        .unwrap();
+    let expected_url_encoded =
+        format!("projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}");
+    assert_eq!(expected_url_encoded, request.to_url());
+    assert_eq!(None, request.body().unwrap());
+}
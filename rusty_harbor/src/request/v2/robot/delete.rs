@@ -0,0 +1,30 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// This endpoint deletes specific robot account information by robot ID.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "robots/{robot_id}",
+    method = "DELETE",
+    response = (),
+)]
+pub struct DeleteRobot {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Robot ID
+    #[serde(skip)]
+    pub robot_id: i64,
+}
+
+impl DeleteRobot {
+    pub fn builder(robot_id: i64) -> DeleteRobotBuilder {
+        DeleteRobotBuilder::default().robot_id(robot_id)
+    }
+}
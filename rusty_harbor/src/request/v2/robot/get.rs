@@ -0,0 +1,80 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{
+        HarborRequest, Paginated,
+        sort::{RobotSortField, Sort},
+    },
+    response::v2::robot::Robot,
+};
+
+/// This endpoint returns specific robot account information by robot ID.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "robots/{robot_id}",
+    method = "GET",
+    response = Robot,
+)]
+pub struct GetRobot {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Robot ID
+    #[serde(skip)]
+    pub robot_id: i64,
+}
+
+impl GetRobot {
+    pub fn builder(robot_id: i64) -> GetRobotBuilder {
+        GetRobotBuilder::default().robot_id(robot_id)
+    }
+}
+
+/// List the robot accounts with the specified level and project.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "robots",
+    method = "GET",
+    response = Vec<Robot>,
+)]
+pub struct GetRobots {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Query string to query resources. Supported query patterns are "exact match(k=v)",
+    /// "fuzzy match(k=~v)", "range(k=[min~max])", "list with union releationship(k={v1 v2 v3})"
+    /// and "list with intersetion relationship(k=(v1 v2 v3))". The value of range and list can be
+    /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
+    /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
+    /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query), e.g. the robots of a project
+    /// with `Level="project",ProjectID=1`.
+    #[builder(default)]
+    pub q: Option<String>,
+    /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
+    /// order and field2 in descending order with "sort=field1,-field2".
+    #[builder(default)]
+    pub sort: Option<Sort<RobotSortField>>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+}
+
+impl GetRobots {
+    pub fn builder() -> GetRobotsBuilder {
+        GetRobotsBuilder::default()
+    }
+}
@@ -0,0 +1,38 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{request::HarborRequest, response::v2::robot::RobotSecret};
+
+/// Refresh the robot secret.
+///
+/// Harbor generates a new secret unless one is given. Clients authenticating as the robot
+/// can switch to it with [`set_auth`](crate::client::HarborClient::set_auth).
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "robots/{robot_id}",
+    method = "PATCH",
+    response = RobotSecret,
+)]
+pub struct RefreshRobotSecret {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Robot ID
+    #[serde(skip)]
+    pub robot_id: i64,
+    /// The new secret of the robot. It has to be at least 8 characters long, and contain an
+    /// uppercase letter, a lowercase letter and a number.
+    #[builder(default)]
+    #[body(field)]
+    pub secret: Option<String>,
+}
+
+impl RefreshRobotSecret {
+    pub fn builder(robot_id: i64) -> RefreshRobotSecretBuilder {
+        RefreshRobotSecretBuilder::default().robot_id(robot_id)
+    }
+}
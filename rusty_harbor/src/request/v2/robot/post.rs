@@ -0,0 +1,33 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{HarborRequest, v2::robot::types::RobotReq},
+    response::v2::robot::RobotCreated,
+};
+
+/// Create a robot account.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "robots",
+    method = "POST",
+    response = RobotCreated,
+)]
+pub struct CreateRobot {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The JSON object of a robot account.
+    #[body]
+    pub robot: RobotReq,
+}
+
+impl CreateRobot {
+    pub fn builder(robot: RobotReq) -> CreateRobotBuilder {
+        CreateRobotBuilder::default().robot(robot)
+    }
+}
@@ -0,0 +1,36 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::{HarborRequest, v2::robot::types::RobotReq};
+
+/// This endpoint updates specific robot account information by robot ID.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "robots/{robot_id}",
+    method = "PUT",
+    response = (),
+)]
+pub struct UpdateRobot {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Robot ID
+    #[serde(skip)]
+    pub robot_id: i64,
+    /// The JSON object of a robot account. Harbor replaces the robot with it, so it has to
+    /// contain the name, level, duration and permissions as well.
+    #[body]
+    pub robot: RobotReq,
+}
+
+impl UpdateRobot {
+    pub fn builder(robot_id: i64, robot: RobotReq) -> UpdateRobotBuilder {
+        UpdateRobotBuilder::default()
+            .robot_id(robot_id)
+            .robot(robot)
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{
+    request::{
+        HarborRequest,
+        query::Query,
+        sort::RobotSortField,
+        v2::robot::{
+            delete::DeleteRobot,
+            get::{GetRobot, GetRobots},
+            patch::RefreshRobotSecret,
+            post::CreateRobot,
+            put::UpdateRobot,
+            types::RobotReq,
+        },
+    },
+    response::v2::robot::{Access, Action, Resource, RobotDuration, RobotLevel, RobotPermission},
+};
+
+const ROBOT_ID: i64 = 12;
+
+fn ci_robot() -> RobotReq {
+    RobotReq::builder()
+        .name("ci")
+        .level(RobotLevel::Project)
+        .duration(RobotDuration::Days(30))
+        .permissions(vec![RobotPermission::project(
+            "library",
+            vec![
+                Access::new(Resource::Repository, Action::Pull),
+                Access::new(Resource::Repository, Action::Push),
+            ],
+        )])
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn get_robot_request_with_builder() {
+    let request = GetRobot::builder(ROBOT_ID).build().unwrap();
+    assert_eq!("robots/12", request.to_url())
+}
+
+#[test]
+fn get_project_robots_request() {
+    let request = GetRobots::builder()
+        .q(Query::new().eq("Level", "project").eq("ProjectID", 1))
+        .sort(RobotSortField::Name)
+        .build()
+        .unwrap();
+    assert_eq!(
+        "robots?q=Level%3D%22project%22%2CProjectID%3D1&sort=name",
+        request.to_url()
+    )
+}
+
+#[test]
+fn create_project_robot_request() {
+    let request = CreateRobot::builder(ci_robot()).build().unwrap();
+    assert_eq!("robots", request.to_url());
+    let expected_body = concat!(
+        r#"{"name":"ci","level":"project","duration":30,"permissions":[{"kind":"project","#,
+        r#""namespace":"library","access":[{"resource":"repository","action":"pull"},"#,
+        r#"{"resource":"repository","action":"push"}]}]}"#
+    );
+    assert_eq!(Some(expected_body.to_string()), request.body().unwrap());
+}
+
+#[test]
+fn create_system_robot_request() {
+    let robot = RobotReq::builder()
+        .name("scanner")
+        .level(RobotLevel::System)
+        .duration(RobotDuration::Never)
+        .permissions(vec![
+            RobotPermission::system(vec![Access::new(
+                Resource::Other("scan-all".to_string()),
+                Action::Create,
+            )]),
+            RobotPermission::project("*", vec![Access::new(Resource::Scan, Action::Create)]),
+        ])
+        .build()
+        .unwrap();
+    let request = CreateRobot::builder(robot).build().unwrap();
+    let expected_body = concat!(
+        r#"{"name":"scanner","level":"system","duration":-1,"permissions":["#,
+        r#"{"kind":"system","namespace":"/","access":[{"resource":"scan-all","action":"create"}]},"#,
+        r#"{"kind":"project","namespace":"*","access":[{"resource":"scan","action":"create"}]}]}"#
+    );
+    assert_eq!(Some(expected_body.to_string()), request.body().unwrap());
+}
+
+#[test]
+fn update_robot_request_with_builder() {
+    let request = UpdateRobot::builder(ROBOT_ID, ci_robot()).build().unwrap();
+    assert_eq!("robots/12", request.to_url());
+}
+
+#[test]
+fn refresh_robot_secret_request() {
+    let request = RefreshRobotSecret::builder(ROBOT_ID).build().unwrap();
+    assert_eq!("robots/12", request.to_url());
+    assert_eq!(Some("{}".to_string()), request.body().unwrap());
+
+    let request = RefreshRobotSecret::builder(ROBOT_ID)
+        .secret("N3w-Secret")
+        .build()
+        .unwrap();
+    assert_eq!(
+        Some(r#"{"secret":"N3w-Secret"}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn delete_robot_request_with_builder() {
+    let request = DeleteRobot::builder(ROBOT_ID).build().unwrap();
+    assert_eq!("robots/12", request.to_url())
+}
@@ -0,0 +1,40 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::response::v2::robot::{RobotDuration, RobotLevel, RobotPermission};
+
+/// The JSON object of a robot account, used to create or update it.
+#[derive(Builder, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+pub struct RobotReq {
+    /// The name of the robot, without the robot prefix.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the robot.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the robot belongs to the system or to a project.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<RobotLevel>,
+    /// Whether the robot is disabled.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable: Option<bool>,
+    /// The duration of the robot.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<RobotDuration>,
+    /// The permissions of the robot.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<RobotPermission>>,
+}
+
+impl RobotReq {
+    pub fn builder() -> RobotReqBuilder {
+        RobotReqBuilder::default()
+    }
+}
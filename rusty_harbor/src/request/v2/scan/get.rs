@@ -0,0 +1,50 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// Get the log of the scan report, as plain text.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}/scan/{report_id}/log",
+    method = "GET",
+    response = String,
+)]
+pub struct GetScanReportLog {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+    /// The report id to get the log.
+    #[serde(skip)]
+    pub report_id: String,
+}
+
+impl GetScanReportLog {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+        report_id: impl Into<String>,
+    ) -> GetScanReportLogBuilder {
+        GetScanReportLogBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+            .report_id(report_id)
+    }
+}
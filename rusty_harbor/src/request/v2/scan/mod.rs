@@ -0,0 +1,4 @@
+pub mod get;
+pub mod post;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,94 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{request::HarborRequest, response::v2::scan::ScanType};
+
+/// Scan the specified artifact. The scan runs in the background; its progress is reported in the
+/// scan overview of the artifact.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}/scan",
+    method = "POST",
+    response = (),
+)]
+pub struct ScanArtifact {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+    /// The type of the scan, supported since Harbor 2.11. Harbor scans for vulnerabilities when
+    /// it is not set.
+    #[builder(default)]
+    #[body(field)]
+    pub scan_type: Option<ScanType>,
+}
+
+impl ScanArtifact {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+    ) -> ScanArtifactBuilder {
+        ScanArtifactBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+    }
+}
+
+/// Cancelling a scan job for a particular artifact.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "projects/{project_name}/repositories/{repository_name}/artifacts/{reference}/scan/stop",
+    method = "POST",
+    response = (),
+)]
+pub struct StopScanArtifact {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the project.
+    #[serde(skip)]
+    pub project_name: String,
+    /// The name of the repository. If it contains slash, it will be encoded twice, e.g. a/b
+    /// becomes a%252Fb.
+    #[serde(skip)]
+    #[harbor(double_encode)]
+    pub repository_name: String,
+    /// The reference of the artifact, can be digest or tag.
+    #[serde(skip)]
+    pub reference: String,
+    /// The type of the scan to stop, required since Harbor 2.11.
+    #[builder(default)]
+    #[body(field)]
+    pub scan_type: Option<ScanType>,
+}
+
+impl StopScanArtifact {
+    pub fn builder(
+        project_name: impl Into<String>,
+        repository_name: impl Into<String>,
+        reference: impl Into<String>,
+    ) -> StopScanArtifactBuilder {
+        StopScanArtifactBuilder::default()
+            .project_name(project_name)
+            .repository_name(repository_name)
+            .reference(reference)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    request::{
+        HarborRequest,
+        v2::{
+            artifact::get::GetVulnerabilitiesAddition,
+            scan::{
+                get::GetScanReportLog,
+                post::{ScanArtifact, StopScanArtifact},
+            },
+        },
+    },
+    response::v2::scan::ScanType,
+};
+
+const PROJECT_NAME: &str = "some-project-name";
+const REPOSITORY_NAME: &str = "some/repository";
+const ENCODED_REPOSITORY_NAME: &str = "some%252Frepository";
+const TAG: &str = "v1.0.0";
+
+#[test]
+fn scan_artifact_request_with_builder() {
+    let request = ScanArtifact::builder(PROJECT_NAME, REPOSITORY_NAME, TAG)
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{TAG}/scan"
+    );
+    assert_eq!(expected_url_encoded, request.to_url());
+    assert_eq!(Some("{}".to_string()), request.body().unwrap());
+}
+
+#[test]
+fn scan_artifact_request_with_scan_type() {
+    let request = ScanArtifact::builder(PROJECT_NAME, REPOSITORY_NAME, TAG)
+        .scan_type(ScanType::Sbom)
+        .build()
+        .unwrap();
+    let expected_body = r#"{"scan_type":"sbom"}"#;
+    assert_eq!(Some(expected_body.to_string()), request.body().unwrap());
+}
+
+#[test]
+fn stop_scan_artifact_request_with_builder() {
+    let request = StopScanArtifact::builder(PROJECT_NAME, REPOSITORY_NAME, TAG)
+        .scan_type(ScanType::Vulnerability)
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{TAG}/scan/stop"
+    );
+    assert_eq!(expected_url_encoded, request.to_url());
+    let expected_body = r#"{"scan_type":"vulnerability"}"#;
+    assert_eq!(Some(expected_body.to_string()), request.body().unwrap());
+}
+
+#[test]
+fn get_scan_report_log_request_with_builder() {
+    let request = GetScanReportLog::builder(PROJECT_NAME, REPOSITORY_NAME, TAG, "report-id")
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{TAG}/scan/report-id/log"
+    );
+    assert_eq!(expected_url_encoded, request.to_url());
+}
+
+#[test]
+fn get_vulnerabilities_addition_request_with_builder() {
+    let request = GetVulnerabilitiesAddition::builder(PROJECT_NAME, REPOSITORY_NAME, TAG)
+        .build()
+        .unwrap();
+    let expected_url_encoded = format!(
+        "projects/{PROJECT_NAME}/repositories/{ENCODED_REPOSITORY_NAME}/artifacts/{TAG}/additions/vulnerabilities"
+    );
+    assert_eq!(expected_url_encoded, request.to_url());
+}
@@ -11,6 +11,7 @@ use crate::{request::HarborRequest, response::v2::search::Search};
 #[derive(Builder, Harbor, Serialize)]
 #[harbor(
     url = "search",
+    method = "GET",
     response = Search,
 )]
 pub struct GetSearch {
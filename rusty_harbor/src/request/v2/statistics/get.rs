@@ -8,6 +8,7 @@ use crate::{request::HarborRequest, response::v2::statistics::Statistic};
 #[derive(Builder, Harbor, Serialize)]
 #[harbor(
     url = "statistics",
+    method = "GET",
     response = Statistic,
 )]
 pub struct GetStatistics {
@@ -0,0 +1,31 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// This endpoint let administrator of Harbor mark a registered user as removed. It actually
+/// won't be deleted from DB.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users/{user_id}",
+    method = "DELETE",
+    response = (),
+)]
+pub struct DeleteUser {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// User ID
+    #[serde(skip)]
+    pub user_id: i64,
+}
+
+impl DeleteUser {
+    pub fn builder(user_id: i64) -> DeleteUserBuilder {
+        DeleteUserBuilder::default().user_id(user_id)
+    }
+}
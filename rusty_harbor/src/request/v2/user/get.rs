@@ -0,0 +1,160 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{
+        HarborRequest, Paginated,
+        sort::{Sort, UserSortField},
+    },
+    response::v2::user::{Permission, User, UserSearchResult},
+};
+
+/// Get a user's profile.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users/{user_id}",
+    method = "GET",
+    response = User,
+)]
+pub struct GetUser {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// User ID
+    #[serde(skip)]
+    pub user_id: i64,
+}
+
+impl GetUser {
+    pub fn builder(user_id: i64) -> GetUserBuilder {
+        GetUserBuilder::default().user_id(user_id)
+    }
+}
+
+/// List users.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users",
+    method = "GET",
+    response = Vec<User>,
+)]
+pub struct GetUsers {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Query string to query resources. Supported query patterns are "exact match(k=v)",
+    /// "fuzzy match(k=~v)", "range(k=[min~max])", "list with union releationship(k={v1 v2 v3})"
+    /// and "list with intersetion relationship(k=(v1 v2 v3))". The value of range and list can be
+    /// string(enclosed by " or '), integer or time(in format "2020-04-09 02:36:00"). All of these
+    /// query patterns should be put in the query string "q=xxx" and splitted by ",". e.g.
+    /// q=k1=v1,k2=~v2,k3=[min~max].
+    ///
+    /// Can be built with a [`Query`](crate::request::query::Query).
+    #[builder(default)]
+    pub q: Option<String>,
+    /// Sort the resource list in ascending or descending order. e.g. sort by field1 in ascending
+    /// order and field2 in descending order with "sort=field1,-field2".
+    #[builder(default)]
+    pub sort: Option<Sort<UserSortField>>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+}
+
+impl GetUsers {
+    pub fn builder() -> GetUsersBuilder {
+        GetUsersBuilder::default()
+    }
+}
+
+/// This endpoint is to search the users by username. All logged-in users can search users.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users/search",
+    method = "GET",
+    response = Vec<UserSearchResult>,
+)]
+pub struct SearchUsers {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+    /// Username for filtering results.
+    pub username: String,
+}
+
+impl SearchUsers {
+    pub fn builder(username: impl Into<String>) -> SearchUsersBuilder {
+        SearchUsersBuilder::default().username(username)
+    }
+}
+
+/// Get current user info.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users/current",
+    method = "GET",
+    response = User,
+)]
+pub struct GetCurrentUser {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+}
+
+impl GetCurrentUser {
+    pub fn builder() -> GetCurrentUserBuilder {
+        GetCurrentUserBuilder::default()
+    }
+}
+
+/// Get current user permissions.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users/current/permissions",
+    method = "GET",
+    response = Vec<Permission>,
+)]
+pub struct GetCurrentUserPermissions {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The scope for the permission, e.g. "/project/1".
+    #[builder(default)]
+    pub scope: Option<String>,
+    /// If true, the resources in the response are relative to the scope, eg for resource
+    /// '/project/1/repository' if relative is 'true' then the resource in response will be
+    /// 'repository'.
+    #[builder(default)]
+    pub relative: Option<bool>,
+}
+
+impl GetCurrentUserPermissions {
+    pub fn builder() -> GetCurrentUserPermissionsBuilder {
+        GetCurrentUserPermissionsBuilder::default()
+    }
+}
@@ -0,0 +1,6 @@
+pub mod delete;
+pub mod get;
+pub mod post;
+pub mod put;
+#[cfg(test)]
+mod tests;
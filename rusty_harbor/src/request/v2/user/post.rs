@@ -0,0 +1,53 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{request::HarborRequest, response::Created};
+
+/// This API can be used only when the authentication mode is for local DB. When self
+/// registration is disabled.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users",
+    method = "POST",
+    response = Created,
+)]
+pub struct CreateUser {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The name of the user.
+    #[body(field)]
+    pub username: String,
+    /// The email of the user.
+    #[body(field)]
+    pub email: String,
+    /// The real name of the user.
+    #[body(field)]
+    pub realname: String,
+    /// The password of the user.
+    #[body(field)]
+    pub password: String,
+    /// The comment of the user.
+    #[builder(default)]
+    #[body(field)]
+    pub comment: Option<String>,
+}
+
+impl CreateUser {
+    pub fn builder(
+        username: impl Into<String>,
+        email: impl Into<String>,
+        realname: impl Into<String>,
+        password: impl Into<String>,
+    ) -> CreateUserBuilder {
+        CreateUserBuilder::default()
+            .username(username)
+            .email(email)
+            .realname(realname)
+            .password(password)
+    }
+}
@@ -0,0 +1,105 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// Update user's profile.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users/{user_id}",
+    method = "PUT",
+    response = (),
+)]
+pub struct UpdateUserProfile {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// User ID
+    #[serde(skip)]
+    pub user_id: i64,
+    /// The email of the user.
+    #[builder(default)]
+    #[body(field)]
+    pub email: Option<String>,
+    /// The real name of the user.
+    #[builder(default)]
+    #[body(field)]
+    pub realname: Option<String>,
+    /// The comment of the user.
+    #[builder(default)]
+    #[body(field)]
+    pub comment: Option<String>,
+}
+
+impl UpdateUserProfile {
+    pub fn builder(user_id: i64) -> UpdateUserProfileBuilder {
+        UpdateUserProfileBuilder::default().user_id(user_id)
+    }
+}
+
+/// This endpoint is for user to update password. Users with the admin role can change any
+/// user's password. Regular users can change only their own password.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users/{user_id}/password",
+    method = "PUT",
+    response = (),
+)]
+pub struct UpdateUserPassword {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// User ID
+    #[serde(skip)]
+    pub user_id: i64,
+    /// The user's existing password, not needed when an administrator changes it.
+    #[builder(default)]
+    #[body(field)]
+    pub old_password: Option<String>,
+    /// New password for marking as to be updated.
+    #[body(field)]
+    pub new_password: String,
+}
+
+impl UpdateUserPassword {
+    pub fn builder(user_id: i64, new_password: impl Into<String>) -> UpdateUserPasswordBuilder {
+        UpdateUserPasswordBuilder::default()
+            .user_id(user_id)
+            .new_password(new_password)
+    }
+}
+
+/// Update a registered user to change to be an administrator of Harbor.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "users/{user_id}/sysadmin",
+    method = "PUT",
+    response = (),
+)]
+pub struct SetUserSysAdmin {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// User ID
+    #[serde(skip)]
+    pub user_id: i64,
+    /// true-the user is sysadmin, false-the user is not sysadmin.
+    #[body(field)]
+    pub sysadmin_flag: bool,
+}
+
+impl SetUserSysAdmin {
+    pub fn builder(user_id: i64, sysadmin_flag: bool) -> SetUserSysAdminBuilder {
+        SetUserSysAdminBuilder::default()
+            .user_id(user_id)
+            .sysadmin_flag(sysadmin_flag)
+    }
+}
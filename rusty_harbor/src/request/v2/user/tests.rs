@@ -0,0 +1,109 @@
+use crate::request::{
+    HarborRequest,
+    query::Query,
+    sort::UserSortField,
+    v2::user::{
+        delete::DeleteUser,
+        get::{GetCurrentUser, GetCurrentUserPermissions, GetUser, GetUsers, SearchUsers},
+        post::CreateUser,
+        put::{SetUserSysAdmin, UpdateUserPassword, UpdateUserProfile},
+    },
+};
+
+const USER_ID: i64 = 5;
+
+#[test]
+fn get_user_request_with_builder() {
+    let request = GetUser::builder(USER_ID).build().unwrap();
+    assert_eq!("users/5", request.to_url())
+}
+
+#[test]
+fn get_users_request_with_builder() {
+    let request = GetUsers::builder()
+        .q(Query::new().fuzzy("email", "@example.com"))
+        .sort(UserSortField::Username.asc())
+        .page_size(100)
+        .build()
+        .unwrap();
+    assert_eq!(
+        "users?q=email%3D%7E%22%40example.com%22&sort=username&page_size=100",
+        request.to_url()
+    )
+}
+
+#[test]
+fn search_users_request_with_builder() {
+    let request = SearchUsers::builder("alice").build().unwrap();
+    assert_eq!("users/search?username=alice", request.to_url())
+}
+
+#[test]
+fn get_current_user_requests() {
+    let request = GetCurrentUser::builder().build().unwrap();
+    assert_eq!("users/current", request.to_url());
+
+    let request = GetCurrentUserPermissions::builder()
+        .scope("/project/1")
+        .relative(true)
+        .build()
+        .unwrap();
+    assert_eq!(
+        "users/current/permissions?scope=%2Fproject%2F1&relative=true",
+        request.to_url()
+    )
+}
+
+#[test]
+fn create_user_request_with_builder() {
+    let request = CreateUser::builder("alice", "alice@example.com", "Alice", "Passw0rd")
+        .build()
+        .unwrap();
+    assert_eq!("users", request.to_url());
+    let expected_body = concat!(
+        r#"{"email":"alice@example.com","password":"Passw0rd","realname":"Alice","#,
+        r#""username":"alice"}"#
+    );
+    assert_eq!(Some(expected_body.to_string()), request.body().unwrap());
+}
+
+#[test]
+fn update_user_profile_request_with_builder() {
+    let request = UpdateUserProfile::builder(USER_ID)
+        .comment("Left the company")
+        .build()
+        .unwrap();
+    assert_eq!("users/5", request.to_url());
+    assert_eq!(
+        Some(r#"{"comment":"Left the company"}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn update_user_password_request_with_builder() {
+    let request = UpdateUserPassword::builder(USER_ID, "N3wPassword")
+        .build()
+        .unwrap();
+    assert_eq!("users/5/password", request.to_url());
+    assert_eq!(
+        Some(r#"{"new_password":"N3wPassword"}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn set_user_sysadmin_request_with_builder() {
+    let request = SetUserSysAdmin::builder(USER_ID, false).build().unwrap();
+    assert_eq!("users/5/sysadmin", request.to_url());
+    assert_eq!(
+        Some(r#"{"sysadmin_flag":false}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn delete_user_request_with_builder() {
+    let request = DeleteUser::builder(USER_ID).build().unwrap();
+    assert_eq!("users/5", request.to_url())
+}
@@ -0,0 +1,30 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::HarborRequest;
+
+/// Delete user group.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "usergroups/{group_id}",
+    method = "DELETE",
+    response = (),
+)]
+pub struct DeleteUserGroup {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Group ID
+    #[serde(skip)]
+    pub group_id: i64,
+}
+
+impl DeleteUserGroup {
+    pub fn builder(group_id: i64) -> DeleteUserGroupBuilder {
+        DeleteUserGroupBuilder::default().group_id(group_id)
+    }
+}
@@ -0,0 +1,99 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{HarborRequest, Paginated},
+    response::v2::usergroup::{UserGroup, UserGroupSearchResult},
+};
+
+/// Get user group information.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "usergroups/{group_id}",
+    method = "GET",
+    response = UserGroup,
+)]
+pub struct GetUserGroup {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Group ID
+    #[serde(skip)]
+    pub group_id: i64,
+}
+
+impl GetUserGroup {
+    pub fn builder(group_id: i64) -> GetUserGroupBuilder {
+        GetUserGroupBuilder::default().group_id(group_id)
+    }
+}
+
+/// Get all user groups information, it is open for system admin.
+#[derive(Builder, Default, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "usergroups",
+    method = "GET",
+    response = Vec<UserGroup>,
+)]
+pub struct GetUserGroups {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+    /// Search group by LDAP group DN.
+    #[builder(default)]
+    pub ldap_group_dn: Option<String>,
+    /// Group name for filtering results.
+    #[builder(default)]
+    pub group_name: Option<String>,
+}
+
+impl GetUserGroups {
+    pub fn builder() -> GetUserGroupsBuilder {
+        GetUserGroupsBuilder::default()
+    }
+}
+
+/// This endpoint is to search groups by group name. It's open for all authenticated requests.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "usergroups/search",
+    method = "GET",
+    response = Vec<UserGroupSearchResult>,
+)]
+pub struct SearchUserGroups {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The page number.
+    /// Default value: `1`
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The size of per page.
+    /// Default value: `10`
+    #[builder(default)]
+    pub page_size: Option<i32>,
+    /// Group name for filtering results.
+    pub groupname: String,
+}
+
+impl SearchUserGroups {
+    pub fn builder(groupname: impl Into<String>) -> SearchUserGroupsBuilder {
+        SearchUserGroupsBuilder::default().groupname(groupname)
+    }
+}
@@ -0,0 +1,33 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    request::{HarborRequest, v2::usergroup::types::UserGroupReq},
+    response::Created,
+};
+
+/// Create user group information.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "usergroups",
+    method = "POST",
+    response = Created,
+)]
+pub struct CreateUserGroup {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// The user group to create.
+    #[body]
+    pub usergroup: UserGroupReq,
+}
+
+impl CreateUserGroup {
+    pub fn builder(usergroup: UserGroupReq) -> CreateUserGroupBuilder {
+        CreateUserGroupBuilder::default().usergroup(usergroup)
+    }
+}
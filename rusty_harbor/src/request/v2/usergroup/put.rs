@@ -0,0 +1,35 @@
+use derive_builder::Builder;
+use derive_harbor::Harbor;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use crate::request::{HarborRequest, v2::usergroup::types::UserGroupReq};
+
+/// Update user group information.
+#[derive(Builder, Harbor, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+#[harbor(
+    url = "usergroups/{group_id}",
+    method = "PUT",
+    response = (),
+)]
+pub struct UpdateUserGroup {
+    /// An unique ID for the request.
+    #[builder(default)]
+    #[header(rename = "X-Request-Id")]
+    pub request_id: Option<String>,
+    /// Group ID
+    #[serde(skip)]
+    pub group_id: i64,
+    /// The updates of the user group. Only the group name can be changed.
+    #[body]
+    pub usergroup: UserGroupReq,
+}
+
+impl UpdateUserGroup {
+    pub fn builder(group_id: i64, usergroup: UserGroupReq) -> UpdateUserGroupBuilder {
+        UpdateUserGroupBuilder::default()
+            .group_id(group_id)
+            .usergroup(usergroup)
+    }
+}
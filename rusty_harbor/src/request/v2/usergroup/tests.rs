@@ -0,0 +1,74 @@
+use crate::{
+    request::{
+        HarborRequest,
+        v2::usergroup::{
+            delete::DeleteUserGroup,
+            get::{GetUserGroup, GetUserGroups, SearchUserGroups},
+            post::CreateUserGroup,
+            put::UpdateUserGroup,
+            types::UserGroupReq,
+        },
+    },
+    response::v2::usergroup::GroupType,
+};
+
+const GROUP_ID: i64 = 3;
+
+#[test]
+fn get_user_group_request_with_builder() {
+    let request = GetUserGroup::builder(GROUP_ID).build().unwrap();
+    assert_eq!("usergroups/3", request.to_url())
+}
+
+#[test]
+fn get_user_groups_request_with_builder() {
+    let request = GetUserGroups::builder()
+        .group_name("qa")
+        .page(2)
+        .build()
+        .unwrap();
+    assert_eq!("usergroups?page=2&group_name=qa", request.to_url())
+}
+
+#[test]
+fn search_user_groups_request_with_builder() {
+    let request = SearchUserGroups::builder("qa").build().unwrap();
+    assert_eq!("usergroups/search?groupname=qa", request.to_url())
+}
+
+#[test]
+fn create_oidc_user_group_request() {
+    let usergroup = UserGroupReq::builder()
+        .group_name("platform")
+        .group_type(GroupType::Oidc)
+        .build()
+        .unwrap();
+    let request = CreateUserGroup::builder(usergroup).build().unwrap();
+    assert_eq!("usergroups", request.to_url());
+    assert_eq!(
+        Some(r#"{"group_name":"platform","group_type":3}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn update_user_group_request_with_builder() {
+    let usergroup = UserGroupReq::builder()
+        .group_name("platform-team")
+        .build()
+        .unwrap();
+    let request = UpdateUserGroup::builder(GROUP_ID, usergroup)
+        .build()
+        .unwrap();
+    assert_eq!("usergroups/3", request.to_url());
+    assert_eq!(
+        Some(r#"{"group_name":"platform-team"}"#.to_string()),
+        request.body().unwrap()
+    );
+}
+
+#[test]
+fn delete_user_group_request_with_builder() {
+    let request = DeleteUserGroup::builder(GROUP_ID).build().unwrap();
+    assert_eq!("usergroups/3", request.to_url())
+}
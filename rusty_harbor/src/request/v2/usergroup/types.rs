@@ -0,0 +1,35 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::response::v2::usergroup::GroupType;
+
+/// The JSON object of a user group, used to create or update it, or to add it to a project.
+///
+/// The id is used if the group already exists in the Harbor database, otherwise the group is
+/// searched for by ldap_group_dn (or group_name) and onboarded.
+#[derive(Builder, Debug, Default, Serialize)]
+#[builder(setter(into, strip_option), pattern = "owned")]
+pub struct UserGroupReq {
+    /// The ID of the user group.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// The name of the user group.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+    /// The type of the user group.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_type: Option<GroupType>,
+    /// The DN of the LDAP group if group type is LDAP.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_group_dn: Option<String>,
+}
+
+impl UserGroupReq {
+    pub fn builder() -> UserGroupReqBuilder {
+        UserGroupReqBuilder::default()
+    }
+}
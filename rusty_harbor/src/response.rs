@@ -1 +1,81 @@
+use reqwest::{
+    StatusCode,
+    header::{HeaderMap, LOCATION},
+};
+use serde::Deserialize;
+
+pub(crate) mod string_bool;
+#[cfg(test)]
+mod tests;
 pub mod v2;
+
+/// Response to requests that create a resource, e.g.
+/// [`CreateProject`](crate::request::v2::project::post::CreateProject).
+///
+/// Harbor responds to those with `201 Created` and an empty body, so the response is taken from
+/// the `Location` header, e.g. `/api/v2.0/projects/42`.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct Created {
+    /// The location of the created resource.
+    pub location: String,
+}
+
+impl Created {
+    /// The last segment of the location, which is the ID of the created resource (or its name,
+    /// when the name was requested in the location).
+    pub fn resource(&self) -> &str {
+        self.location.rsplit('/').next().unwrap_or_default()
+    }
+
+    /// The ID of the created resource, if the location ends with one.
+    pub fn id(&self) -> Option<i64> {
+        self.resource().parse().ok()
+    }
+}
+
+/// Header containing the total number of items matching a list request.
+pub const X_TOTAL_COUNT: &str = "X-Total-Count";
+
+/// Header containing the unique ID of the request, as sent by the client or generated by Harbor.
+pub const X_REQUEST_ID: &str = "X-Request-Id";
+
+/// A deserialized response body, along with the status and headers of the response.
+///
+/// Returned by [`send_with_meta`](crate::client::HarborClient::send_with_meta).
+#[derive(Debug)]
+pub struct HarborResponse<T> {
+    /// The status code of the response.
+    pub status: StatusCode,
+    /// All headers of the response.
+    pub headers: HeaderMap,
+    /// The deserialized body of the response.
+    pub body: T,
+}
+
+impl<T> HarborResponse<T> {
+    /// Discard the status and headers, keeping only the deserialized body.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+
+    /// Get the value of a header, if it is present and valid ASCII.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    /// The total number of items matching a list request, from the `X-Total-Count` header.
+    pub fn total_count(&self) -> Option<u64> {
+        self.header(X_TOTAL_COUNT)?.trim().parse().ok()
+    }
+
+    /// The location of a created resource, from the `Location` header.
+    pub fn location(&self) -> Option<&str> {
+        self.header(LOCATION.as_str())
+    }
+
+    /// The unique ID of the request, from the `X-Request-Id` header.
+    pub fn request_id(&self) -> Option<&str> {
+        self.header(X_REQUEST_ID)
+    }
+}
@@ -0,0 +1,59 @@
+//! Serde adapter for booleans Harbor encodes as the strings `"true"` and `"false"`, e.g. in
+//! [`ProjectMetadata`](crate::response::v2::project::types::ProjectMetadata) and
+//! [`ProjectMetadataReq`](crate::request::v2::project::types::ProjectMetadataReq).
+//!
+//! Use with `#[serde(with = "crate::response::string_bool")]`, or with
+//! `#[serde(default, with = "crate::response::string_bool::option")]` for optional fields.
+
+use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+/// Harbor encodes these booleans as strings, but accept actual booleans as well.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrBool {
+    String(String),
+    Bool(bool),
+}
+
+impl StringOrBool {
+    fn into_bool<E: Error>(self) -> Result<bool, E> {
+        match self {
+            StringOrBool::Bool(value) => Ok(value),
+            StringOrBool::String(value) => value
+                .parse()
+                .map_err(|_| E::custom(format!("expected \"true\" or \"false\", got {value:?}"))),
+        }
+    }
+}
+
+pub fn serialize<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(if *value { "true" } else { "false" })
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    StringOrBool::deserialize(deserializer)?.into_bool()
+}
+
+pub mod option {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::StringOrBool;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<bool>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => super::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<bool>, D::Error> {
+        Option::<StringOrBool>::deserialize(deserializer)?
+            .map(StringOrBool::into_bool)
+            .transpose()
+    }
+}
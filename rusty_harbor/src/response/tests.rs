@@ -0,0 +1,44 @@
+use reqwest::{
+    StatusCode,
+    header::{HeaderMap, HeaderValue, LOCATION},
+};
+
+use crate::response::{Created, HarborResponse};
+
+fn response_with_headers(headers: &[(&'static str, &'static str)]) -> HarborResponse<()> {
+    let mut header_map = HeaderMap::new();
+    for (name, value) in headers {
+        header_map.insert(*name, HeaderValue::from_static(value));
+    }
+    HarborResponse {
+        status: StatusCode::OK,
+        headers: header_map,
+        body: (),
+    }
+}
+
+#[test]
+fn total_count_is_read_from_header() {
+    let response = response_with_headers(&[("x-total-count", "128")]);
+    assert_eq!(Some(128), response.total_count());
+}
+
+#[test]
+fn request_id_is_read_from_header() {
+    let response = response_with_headers(&[("x-request-id", "some-request-id")]);
+    assert_eq!(Some("some-request-id"), response.request_id());
+    assert_eq!(None, response.total_count());
+}
+
+#[test]
+fn location_is_read_from_header() {
+    let response = response_with_headers(&[(LOCATION.as_str(), "/api/v2.0/projects/42")]);
+    assert_eq!(Some("/api/v2.0/projects/42"), response.location());
+}
+
+#[test]
+fn created_id_is_parsed_from_location() {
+    let created: Created = serde_json::from_str(r#""/api/v2.0/projects/42""#).unwrap();
+    assert_eq!(Some(42), created.id());
+    assert_eq!("42", created.resource());
+}
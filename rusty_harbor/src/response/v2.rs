@@ -1,5 +1,14 @@
 pub mod configure;
 pub mod health;
+pub mod member;
 pub mod project;
+pub mod registry;
+pub mod repository;
+pub mod robot;
+pub mod scan;
 pub mod search;
 pub mod statistics;
+#[cfg(test)]
+mod tests;
+pub mod user;
+pub mod usergroup;
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+/// Response to requests that request project member(s):
+/// - [`GetProjectMember`](crate::request::v2::member::get::GetProjectMember)
+/// - [`GetProjectMembers`](crate::request::v2::member::get::GetProjectMembers) (in a vector)
+#[derive(Debug, Deserialize)]
+pub struct ProjectMember {
+    /// The ID of the member.
+    pub id: i64,
+    /// The ID of the project the member belongs to.
+    pub project_id: i64,
+    /// The name of the user or group.
+    pub entity_name: Option<String>,
+    /// The name of the role of the member, e.g. "developer".
+    pub role_name: Option<String>,
+    /// The role of the member.
+    pub role_id: Role,
+    /// The ID of the user or group.
+    pub entity_id: Option<i64>,
+    /// Whether the member is a user or a group.
+    pub entity_type: Option<EntityType>,
+}
+
+/// Whether a project member is a single user or a user group.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum EntityType {
+    #[serde(rename = "u")]
+    User,
+    #[serde(rename = "g")]
+    Group,
+}
+
+/// The role of a member in a project, from the most to the least privileged. Harbor identifies
+/// roles by ID.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[serde(from = "i64", into = "i64")]
+pub enum Role {
+    /// Full control over the project, including its members.
+    ProjectAdmin,
+    /// Can push and pull, and manage the artifacts of the project.
+    Maintainer,
+    /// Can push and pull artifacts.
+    Developer,
+    /// Can pull artifacts.
+    Guest,
+    /// Can pull artifacts, but not list the other members or see the project logs.
+    LimitedGuest,
+    /// Any role that is not covered by the other variants, by ID.
+    Other(i64),
+}
+
+impl Role {
+    /// The ID Harbor identifies the role with.
+    pub fn id(&self) -> i64 {
+        match self {
+            Role::ProjectAdmin => 1,
+            Role::Developer => 2,
+            Role::Guest => 3,
+            Role::Maintainer => 4,
+            Role::LimitedGuest => 5,
+            Role::Other(id) => *id,
+        }
+    }
+}
+
+impl From<i64> for Role {
+    fn from(id: i64) -> Self {
+        match id {
+            1 => Role::ProjectAdmin,
+            2 => Role::Developer,
+            3 => Role::Guest,
+            4 => Role::Maintainer,
+            5 => Role::LimitedGuest,
+            _ => Role::Other(id),
+        }
+    }
+}
+
+impl From<Role> for i64 {
+    fn from(role: Role) -> Self {
+        role.id()
+    }
+}
@@ -1,12 +1,14 @@
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
+use crate::response::v2::registry::Registry;
+
 pub mod maps;
 pub mod types;
 
 use maps::{AdditionLinks, Annotations, ExtraAttrs, ScanOverview};
 use types::{
-    CveAllowlist, Label, ProjectMetadata, ProjectSummaryQuota, Reference, Registry, SbomOverview,
+    Accessory, CveAllowlist, Label, ProjectMetadata, ProjectSummaryQuota, Reference, SbomOverview,
     Tag,
 };
 
@@ -38,7 +40,8 @@ pub struct Project {
     pub current_user_role_id: Option<u32>,
     /// The list of role ID of the current user who triggered the API (for UI).
     pub current_user_role_ids: Option<Vec<u32>>,
-    /// The number of the repositories under this project.
+    /// The number of the repositories under this project. Harbor omits it for empty projects.
+    #[serde(default)]
     pub repo_count: u32,
     /// Project metadata.
     pub metadata: ProjectMetadata,
@@ -53,7 +56,7 @@ pub struct ProjectDeletable {
     /// Whether the project can be deleted.
     pub deletable: bool,
     /// The detail message when the project can not be deleted.
-    pub message: String,
+    pub message: Option<String>,
 }
 
 /// Respone to the [`GetProjectSummary`](crate::request::v2::project::get::GetProjectSummary) request.
@@ -75,8 +78,10 @@ pub struct ProjectSummary {
     pub registry: Option<Registry>,
 }
 
-/// Response to the [`GetProjectArtifacts`](crate::request::v2::project::get::GetProjectArtifacts)
-/// request (in a vector).
+/// Response to requests that request artifact(s):
+/// - [`GetArtifact`](crate::request::v2::artifact::get::GetArtifact)
+/// - [`GetArtifacts`](crate::request::v2::artifact::get::GetArtifacts) (in a vector)
+/// - [`GetProjectArtifacts`](crate::request::v2::project::get::GetProjectArtifacts) (in a vector)
 #[derive(Debug, Deserialize)]
 pub struct Artifact {
     /// The ID of the artifact.
@@ -117,5 +122,5 @@ pub struct Artifact {
     /// The generate SBOM overview information.
     pub sbom_overview: Option<SbomOverview>,
     /// The accessory of the artifact.
-    pub accessories: Option<Vec<Artifact>>,
+    pub accessories: Option<Vec<Accessory>>,
 }
@@ -1,5 +1,7 @@
+use std::fmt;
+
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::response::v2::project::maps::{Annotations, ResourceList, Summary};
 
@@ -64,6 +66,8 @@ pub struct CveAllowlistItem {
     pub cve_id: String,
 }
 
+/// Response to the [`GetLabel`](crate::request::v2::label::get::GetLabel) request, and to the
+/// [`GetLabels`](crate::request::v2::label::get::GetLabels) request (in a vector).
 #[derive(Debug, Deserialize)]
 pub struct Label {
     /// The ID of the label.
@@ -75,7 +79,7 @@ pub struct Label {
     /// The color the label.
     pub color: Option<String>,
     /// The scope the label.
-    pub scope: Option<String>,
+    pub scope: Option<LabelScope>,
     /// The ID of project that the label belongs to.
     pub project_id: Option<i64>,
     /// The creation time the label.
@@ -84,6 +88,34 @@ pub struct Label {
     pub update_time: Option<DateTime<Utc>>,
 }
 
+/// Whether a label can be used by artifacts of all projects, or only of the project it belongs
+/// to.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum LabelScope {
+    /// A system-wide label, which only system administrators can manage.
+    #[serde(rename = "g")]
+    Global,
+    /// A label of a single project, identified by its `project_id`.
+    #[serde(rename = "p")]
+    Project,
+}
+
+impl LabelScope {
+    /// The abbreviation Harbor uses for the scope.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LabelScope::Global => "g",
+            LabelScope::Project => "p",
+        }
+    }
+}
+
+impl fmt::Display for LabelScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct NativeReportSummary {
     /// ID of the native scan report.
@@ -91,10 +123,10 @@ pub struct NativeReportSummary {
     pub report_id: Option<String>,
     /// The status of the report generating process
     /// Example: Success
-    pub scan_status: Option<String>,
+    pub scan_status: Option<ScanStatus>,
     /// The overall severity.
     /// Example: High
-    pub severity: Option<String>,
+    pub severity: Option<Severity>,
     /// The seconds spent for generating the report.
     /// Example: 300
     pub duration: Option<i64>,
@@ -132,28 +164,34 @@ pub struct Platform {
 /// Project metadata.
 #[derive(Debug, Deserialize)]
 pub struct ProjectMetadata {
-    /// The public status of the project. The valid values are "true", "false".
-    pub public: String,
+    /// The public status of the project.
+    #[serde(with = "crate::response::string_bool")]
+    pub public: bool,
     /// Whether content trust is enabled or not. If it is enabled, user can't pull unsigned images
-    /// from this project. The valid values are "true", "false".
-    pub enable_content_trust: Option<String>,
+    /// from this project.
+    #[serde(default, with = "crate::response::string_bool::option")]
+    pub enable_content_trust: Option<bool>,
     /// Whether cosign content trust is enabled or not. If it is enabled, user can't pull images
-    /// without cosign signature from this project. The valid values are "true", "false".
-    pub enable_content_trust_cosign: Option<String>,
-    /// Whether prevent the vulnerable images from running. The valid values are "true", "false".
-    pub prevent_vul: Option<String>,
-    /// If the vulnerability is high than severity defined here, the images can't be pulled. The
-    /// valid values are "none", "low", "medium", "high", "critical".
-    pub severity: Option<String>,
-    /// Whether scan images automatically when pushing. The valid values are "true", "false".
-    pub auto_scan: Option<String>,
-    /// Whether generating SBOM automatically when pushing a subject artifact. The valid values are
-    /// "true", "false".
-    pub auto_sbom_generation: Option<String>,
-    /// Whether this project reuse the system level CVE allowlist as the allowlist of its own. The
-    /// valid values are "true", "false". If it is set to "true" the actual allowlist associate
-    /// with this project, if any, will be ignored.
-    pub reuse_sys_cve_allowlist: Option<String>,
+    /// without cosign signature from this project.
+    #[serde(default, with = "crate::response::string_bool::option")]
+    pub enable_content_trust_cosign: Option<bool>,
+    /// Whether prevent the vulnerable images from running.
+    #[serde(default, with = "crate::response::string_bool::option")]
+    pub prevent_vul: Option<bool>,
+    /// If the vulnerability is high than severity defined here, the images can't be pulled.
+    #[serde(default)]
+    pub severity: Option<Severity>,
+    /// Whether scan images automatically when pushing.
+    #[serde(default, with = "crate::response::string_bool::option")]
+    pub auto_scan: Option<bool>,
+    /// Whether generating SBOM automatically when pushing a subject artifact.
+    #[serde(default, with = "crate::response::string_bool::option")]
+    pub auto_sbom_generation: Option<bool>,
+    /// Whether this project reuse the system level CVE allowlist as the allowlist of its own. If
+    /// it is set to `true` the actual allowlist associate with this project, if any, will be
+    /// ignored.
+    #[serde(default, with = "crate::response::string_bool::option")]
+    pub reuse_sys_cve_allowlist: Option<bool>,
     /// The ID of the tag retention policy for the project.
     pub retention_id: Option<String>,
     /// The bandwidth limit of proxy cache, in Kbps (kilobits per second). It limits the
@@ -161,6 +199,23 @@ pub struct ProjectMetadata {
     pub proxy_speed_kb: Option<String>,
 }
 
+/// Project metadata encodes the severity in lowercase, e.g. "high", unlike scan reports.
+pub(crate) mod severity_lowercase {
+    use serde::Serializer;
+
+    use super::Severity;
+
+    pub fn serialize<S: Serializer>(
+        severity: &Option<Severity>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match severity {
+            Some(severity) => serializer.serialize_str(&severity.as_str().to_lowercase()),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ProjectSummaryQuota {
     pub hard: Option<ResourceList>,
@@ -181,41 +236,6 @@ pub struct Reference {
     pub urls: Option<Vec<String>>,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct Registry {
-    /// The registry ID.
-    pub id: i64,
-    /// The registry URL string.
-    pub url: String,
-    /// The registry name.
-    pub name: String,
-    pub credential: RegistryCredential,
-    /// Type of the registry, e.g. 'harbor'.
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Whether or not the certificate will be verified when Harbor tries to access the server.
-    pub insecure: bool,
-    /// Description of the registry.
-    pub description: String,
-    /// Health status of the registry.
-    pub status: String,
-    /// The create time of the policy.
-    pub creation_time: DateTime<Utc>,
-    /// The update time of the policy.
-    pub update_time: DateTime<Utc>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct RegistryCredential {
-    /// Credential type, such as 'basic', 'oauth'.
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Access key, e.g. user name when credential type is 'basic'.
-    pub access_key: String,
-    /// Access secret, e.g. password when credential type is 'basic'.
-    pub access_secret: String,
-}
-
 /// The generate SBOM overview information.
 #[derive(Debug, Deserialize)]
 pub struct SbomOverview {
@@ -226,7 +246,7 @@ pub struct SbomOverview {
     /// Example: 2006-01-02T15:04:05Z
     pub end_time: Option<DateTime<Utc>>,
     /// The status of the generating SBOM task.
-    pub scan_status: Option<String>,
+    pub scan_status: Option<ScanStatus>,
     /// The digest of the generated SBOM accessory.
     pub sbom_digest: Option<String>,
     /// ID of the native scan report.
@@ -238,6 +258,58 @@ pub struct SbomOverview {
     pub scanner: Option<Scanner>,
 }
 
+/// The status of a scan (or SBOM generation) job.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum ScanStatus {
+    Pending,
+    Running,
+    Scheduled,
+    Success,
+    Error,
+    Stopped,
+    /// Any status that is not covered by the other variants.
+    Other(String),
+}
+
+impl ScanStatus {
+    /// Whether the job has finished, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            ScanStatus::Success | ScanStatus::Error | ScanStatus::Stopped
+        )
+    }
+}
+
+impl From<String> for ScanStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "Pending" => ScanStatus::Pending,
+            "Running" => ScanStatus::Running,
+            "Scheduled" => ScanStatus::Scheduled,
+            "Success" => ScanStatus::Success,
+            "Error" => ScanStatus::Error,
+            "Stopped" => ScanStatus::Stopped,
+            _ => ScanStatus::Other(status),
+        }
+    }
+}
+
+impl From<ScanStatus> for String {
+    fn from(status: ScanStatus) -> Self {
+        match status {
+            ScanStatus::Pending => "Pending".to_string(),
+            ScanStatus::Running => "Running".to_string(),
+            ScanStatus::Scheduled => "Scheduled".to_string(),
+            ScanStatus::Success => "Success".to_string(),
+            ScanStatus::Error => "Error".to_string(),
+            ScanStatus::Stopped => "Stopped".to_string(),
+            ScanStatus::Other(status) => status,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Scanner {
     /// Name of the scanner.
@@ -251,6 +323,69 @@ pub struct Scanner {
     pub version: Option<String>,
 }
 
+/// The severity of a vulnerability, ordered like Harbor does from [`None`](Severity::None) to
+/// [`Critical`](Severity::Critical), so it can be compared against a threshold.
+///
+/// Harbor reports it capitalized (e.g. "High"), but project metadata uses lowercase (e.g.
+/// "high"); both are accepted. Severities Harbor does not know are kept as
+/// [`Other`](Severity::Other), which ranks right above [`Unknown`](Severity::Unknown) and below
+/// all known levels.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum Severity {
+    None,
+    Unknown,
+    /// Any severity that is not covered by the other variants.
+    Other(String),
+    Negligible,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    /// The capitalized name Harbor reports the severity with.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Severity::None => "None",
+            Severity::Unknown => "Unknown",
+            Severity::Other(severity) => severity,
+            Severity::Negligible => "Negligible",
+            Severity::Low => "Low",
+            Severity::Medium => "Medium",
+            Severity::High => "High",
+            Severity::Critical => "Critical",
+        }
+    }
+}
+
+impl From<String> for Severity {
+    fn from(severity: String) -> Self {
+        match severity.to_lowercase().as_str() {
+            "none" => Severity::None,
+            "unknown" => Severity::Unknown,
+            "negligible" => Severity::Negligible,
+            "low" => Severity::Low,
+            "medium" => Severity::Medium,
+            "high" => Severity::High,
+            "critical" => Severity::Critical,
+            _ => Severity::Other(severity),
+        }
+    }
+}
+
+impl From<Severity> for String {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Other(severity) => severity,
+            severity => severity.as_str().to_string(),
+        }
+    }
+}
+
+/// Response to the [`GetArtifactTags`](crate::request::v2::artifact::get::GetArtifactTags) request
+/// (in a vector).
 #[derive(Debug, Deserialize)]
 pub struct Tag {
     /// The ID of the tag
@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Response to requests that request registry(s):
+/// - [`GetRegistry`](crate::request::v2::registry::get::GetRegistry)
+/// - [`GetRegistries`](crate::request::v2::registry::get::GetRegistries) (in a vector)
+///
+/// Also part of the [`ProjectSummary`](crate::response::v2::project::ProjectSummary) of proxy
+/// cache projects.
+#[derive(Debug, Deserialize)]
+pub struct Registry {
+    /// The registry ID.
+    pub id: i64,
+    /// The registry URL string.
+    pub url: String,
+    /// The registry name.
+    pub name: String,
+    /// The credential of the registry. Harbor omits it for registries accessed anonymously.
+    pub credential: Option<RegistryCredential>,
+    /// Type of the registry, e.g. 'harbor'. One of the
+    /// [registry adapters](crate::request::v2::registry::get::GetRegistryAdapters).
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Whether or not the certificate will be verified when Harbor tries to access the server.
+    pub insecure: bool,
+    /// Description of the registry. Harbor omits it when empty.
+    #[serde(default)]
+    pub description: String,
+    /// Health status of the registry.
+    pub status: String,
+    /// The create time of the policy.
+    pub creation_time: DateTime<Utc>,
+    /// The update time of the policy.
+    pub update_time: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegistryCredential {
+    /// Credential type, such as 'basic', 'oauth'.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Access key, e.g. user name when credential type is 'basic'.
+    #[serde(default)]
+    pub access_key: String,
+    /// Access secret, e.g. password when credential type is 'basic'. Harbor masks it in
+    /// responses.
+    #[serde(default)]
+    pub access_secret: String,
+}
+
+/// Response to the [`GetRegistryInfo`](crate::request::v2::registry::get::GetRegistryInfo)
+/// request.
+#[derive(Debug, Deserialize)]
+pub struct RegistryInfo {
+    /// The registry type.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The description.
+    #[serde(default)]
+    pub description: String,
+    /// The filters that the registry supports.
+    #[serde(default)]
+    pub supported_resource_filters: Vec<FilterStyle>,
+    /// The triggers that the registry supports.
+    #[serde(default)]
+    pub supported_triggers: Vec<String>,
+}
+
+/// A filter of the resources of a registry that replication policies can use.
+#[derive(Debug, Deserialize)]
+pub struct FilterStyle {
+    /// The filter type, e.g. 'name' or 'tag'.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The filter style, e.g. 'input' or 'radio'.
+    pub style: String,
+    /// The filter values.
+    #[serde(default)]
+    pub values: Vec<String>,
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Response to requests that request repository(s):
+/// - [`GetRepository`](crate::request::v2::repository::get::GetRepository)
+/// - [`GetRepositories`](crate::request::v2::repository::get::GetRepositories) (in a vector)
+/// - [`GetProjectRepositories`](crate::request::v2::repository::get::GetProjectRepositories) (in
+///   a vector)
+#[derive(Debug, Deserialize)]
+pub struct Repository {
+    /// The ID of the repository.
+    pub id: i64,
+    /// The ID of the project that the repository belongs to.
+    pub project_id: i64,
+    /// The name of the repository, prefixed with the name of its project, e.g. "library/nginx".
+    /// Requests take the name without that prefix as `repository_name`, see
+    /// [`repository_name`](Repository::repository_name).
+    pub name: String,
+    /// The description of the repository.
+    pub description: Option<String>,
+    /// The count of the artifacts inside the repository.
+    pub artifact_count: Option<i64>,
+    /// The count that the artifact inside the repository pulled.
+    pub pull_count: Option<i64>,
+    /// The creation time of the repository.
+    pub creation_time: Option<DateTime<Utc>>,
+    /// The update time of the repository.
+    pub update_time: Option<DateTime<Utc>>,
+}
+
+impl Repository {
+    /// The name of the repository without the name of its project, e.g. "nginx" for
+    /// "library/nginx", as requests take it for `repository_name`.
+    pub fn repository_name(&self) -> &str {
+        self.name
+            .split_once('/')
+            .map_or(self.name.as_str(), |(_, name)| name)
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::client::auth::Auth;
+
+/// Response to requests that request robot account(s):
+/// - [`GetRobot`](crate::request::v2::robot::get::GetRobot)
+/// - [`GetRobots`](crate::request::v2::robot::get::GetRobots) (in a vector)
+#[derive(Debug, Deserialize)]
+pub struct Robot {
+    /// The ID of the robot.
+    pub id: i64,
+    /// The name of the robot, including the robot prefix, e.g. "robot$library+ci".
+    pub name: String,
+    /// The description of the robot.
+    pub description: Option<String>,
+    /// Whether the robot belongs to the system or to a project.
+    pub level: Option<RobotLevel>,
+    /// The duration of the robot.
+    pub duration: Option<RobotDuration>,
+    /// Whether the robot can be edited, robots created before Harbor v2.2 cannot.
+    pub editable: Option<bool>,
+    /// Whether the robot is disabled.
+    pub disable: Option<bool>,
+    /// The expiration date of the robot, as a Unix timestamp. `-1` means it never expires.
+    pub expires_at: Option<i64>,
+    /// The permissions of the robot.
+    #[serde(default)]
+    pub permissions: Vec<RobotPermission>,
+    /// The creation time of the robot.
+    pub creation_time: Option<DateTime<Utc>>,
+    /// The update time of the robot.
+    pub update_time: Option<DateTime<Utc>>,
+}
+
+/// Response to the [`CreateRobot`](crate::request::v2::robot::post::CreateRobot) request.
+///
+/// This is the only time Harbor reveals the secret of the robot, so it has to be stored right
+/// away.
+#[derive(Deserialize)]
+pub struct RobotCreated {
+    /// The ID of the robot.
+    pub id: i64,
+    /// The name of the robot, including the robot prefix, e.g. "robot$library+ci".
+    pub name: String,
+    /// The secret of the robot.
+    pub secret: String,
+    /// The creation time of the robot.
+    pub creation_time: Option<DateTime<Utc>>,
+    /// The expiration date of the robot, as a Unix timestamp. `-1` means it never expires.
+    pub expires_at: Option<i64>,
+}
+
+impl RobotCreated {
+    /// The credentials to authenticate as the robot with.
+    pub fn auth(&self) -> Auth {
+        Auth::basic(&self.name, &self.secret)
+    }
+}
+
+/// Never print the secret.
+impl fmt::Debug for RobotCreated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RobotCreated")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("secret", &"<redacted>")
+            .field("creation_time", &self.creation_time)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Response to the [`RefreshRobotSecret`](crate::request::v2::robot::patch::RefreshRobotSecret)
+/// request.
+#[derive(Deserialize)]
+pub struct RobotSecret {
+    /// The new secret of the robot.
+    pub secret: String,
+}
+
+/// Never print the secret.
+impl fmt::Debug for RobotSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RobotSecret")
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Whether a robot (or one of its permissions) covers the whole system or a single project.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RobotLevel {
+    System,
+    Project,
+}
+
+/// How long a robot is valid after its creation (or after its secret was refreshed).
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(from = "i64", into = "i64")]
+pub enum RobotDuration {
+    /// Valid for the given number of days.
+    Days(i64),
+    /// Never expires.
+    Never,
+}
+
+impl From<i64> for RobotDuration {
+    fn from(days: i64) -> Self {
+        match days {
+            -1 => RobotDuration::Never,
+            days => RobotDuration::Days(days),
+        }
+    }
+}
+
+impl From<RobotDuration> for i64 {
+    fn from(duration: RobotDuration) -> Self {
+        match duration {
+            RobotDuration::Days(days) => days,
+            RobotDuration::Never => -1,
+        }
+    }
+}
+
+/// The access a robot has to the resources of the system, or of a project.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct RobotPermission {
+    /// Whether the permission covers the system or a project.
+    pub kind: RobotLevel,
+    /// The name of the project for project permissions, or "/" for system permissions. "*"
+    /// covers all projects.
+    pub namespace: String,
+    /// The actions allowed on resources.
+    pub access: Vec<Access>,
+}
+
+impl RobotPermission {
+    /// Permissions on the resources of the project with the given name.
+    pub fn project(namespace: impl Into<String>, access: Vec<Access>) -> Self {
+        RobotPermission {
+            kind: RobotLevel::Project,
+            namespace: namespace.into(),
+            access,
+        }
+    }
+
+    /// Permissions on the resources of the system.
+    pub fn system(access: Vec<Access>) -> Self {
+        RobotPermission {
+            kind: RobotLevel::System,
+            namespace: "/".to_string(),
+            access,
+        }
+    }
+}
+
+/// An action allowed on a resource.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct Access {
+    /// The resource the action is allowed on.
+    pub resource: Resource,
+    /// The allowed action.
+    pub action: Action,
+    /// The effect of the access, "allow" or "deny".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect: Option<String>,
+}
+
+impl Access {
+    pub fn new(resource: Resource, action: Action) -> Self {
+        Access {
+            resource,
+            action,
+            effect: None,
+        }
+    }
+}
+
+/// Define an enum of names Harbor uses, with a variant for names it does not cover.
+macro_rules! names {
+    ($(#[$meta:meta])* $name:ident { $($(#[$variant_meta:meta])* $variant:ident => $value:literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// Any name that is not covered by the other variants.
+            Other(String),
+        }
+
+        impl $name {
+            /// The name Harbor uses.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Other(name) => name,
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(name: String) -> Self {
+                match name.as_str() {
+                    $($value => $name::$variant,)*
+                    _ => $name::Other(name),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(name: $name) -> Self {
+                match name {
+                    $name::Other(name) => name,
+                    name => name.as_str().to_string(),
+                }
+            }
+        }
+    };
+}
+
+names! {
+    /// A resource a robot can be allowed to act on.
+    Resource {
+        Repository => "repository",
+        Artifact => "artifact",
+        Tag => "tag",
+        ArtifactLabel => "artifact-label",
+        Accessory => "accessory",
+        Scan => "scan",
+        Sbom => "sbom",
+        Label => "label",
+        Member => "member",
+        Metadata => "metadata",
+        Project => "project",
+        Quota => "quota",
+        Robot => "robot",
+        Log => "log",
+        NotificationPolicy => "notification-policy",
+        ImmutableTag => "immutable-tag",
+        TagRetention => "tag-retention",
+        PreheatPolicy => "preheat-policy",
+        Registry => "registry",
+        Replication => "replication",
+        GarbageCollection => "garbage-collection",
+        Scanner => "scanner",
+        User => "user",
+        UserGroup => "user-group",
+        Configuration => "configuration",
+    }
+}
+
+names! {
+    /// An action a robot can be allowed to take on a resource.
+    Action {
+        Pull => "pull",
+        Push => "push",
+        Read => "read",
+        List => "list",
+        Create => "create",
+        Update => "update",
+        Delete => "delete",
+        Stop => "stop",
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::response::v2::project::types::{Scanner, Severity};
+
+/// The vulnerability reports of an artifact, by the MIME type of the report.
+/// Response to the
+/// [`GetVulnerabilitiesAddition`](crate::request::v2::artifact::get::GetVulnerabilitiesAddition)
+/// request. It is empty when the artifact has not been scanned.
+pub type VulnerabilityReports = HashMap<String, VulnerabilityReport>;
+
+/// The kind of scan to start or stop.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScanType {
+    /// Scan the artifact for vulnerabilities.
+    #[default]
+    Vulnerability,
+    /// Generate a software bill of materials (SBOM) for the artifact.
+    Sbom,
+}
+
+/// The report of a vulnerability scan.
+#[derive(Debug, Deserialize)]
+pub struct VulnerabilityReport {
+    /// The time the report was generated.
+    pub generated_at: Option<DateTime<Utc>>,
+    /// The scanner that generated the report.
+    pub scanner: Option<Scanner>,
+    /// The overall severity of the found vulnerabilities.
+    pub severity: Option<Severity>,
+    /// The found vulnerabilities.
+    #[serde(default)]
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+impl VulnerabilityReport {
+    /// The vulnerabilities with at least the given severity, e.g. to block a release on.
+    pub fn at_least(&self, severity: Severity) -> impl Iterator<Item = &Vulnerability> {
+        self.vulnerabilities
+            .iter()
+            .filter(move |vulnerability| vulnerability.severity.as_ref() >= Some(&severity))
+    }
+}
+
+/// A vulnerability found in a package of an artifact.
+#[derive(Debug, Deserialize)]
+pub struct Vulnerability {
+    /// The ID of the vulnerability, e.g. "CVE-2023-44487".
+    pub id: String,
+    /// The name of the vulnerable package.
+    pub package: String,
+    /// The installed version of the package.
+    pub version: String,
+    /// The version of the package that fixes the vulnerability, if any.
+    pub fix_version: Option<String>,
+    /// The severity of the vulnerability.
+    pub severity: Option<Severity>,
+    /// The description of the vulnerability.
+    pub description: Option<String>,
+    /// Links to more information about the vulnerability.
+    #[serde(default)]
+    pub links: Vec<String>,
+    /// The CVSS scores and vectors the scanner prefers.
+    pub preferred_cvss: Option<Cvss>,
+    /// The CWE IDs of the vulnerability, e.g. "CWE-400".
+    #[serde(default)]
+    pub cwe_ids: Vec<String>,
+    /// The digests of the artifacts the vulnerability was found in.
+    #[serde(default)]
+    pub artifact_digests: Vec<String>,
+    /// Additional attributes of the scanner vendor, e.g. the CVSS scores of every source.
+    pub vendor_attributes: Option<Value>,
+}
+
+/// The CVSS scores and vectors of a vulnerability.
+#[derive(Debug, Deserialize)]
+pub struct Cvss {
+    /// The CVSS v3 base score.
+    pub score_v3: Option<f64>,
+    /// The CVSS v2 base score.
+    pub score_v2: Option<f64>,
+    /// The CVSS v3 vector, e.g. "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:H".
+    pub vector_v3: Option<String>,
+    /// The CVSS v2 vector.
+    pub vector_v2: Option<String>,
+}
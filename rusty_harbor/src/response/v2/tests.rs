@@ -0,0 +1,325 @@
+use std::{fs, path::PathBuf};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    client::auth::Auth,
+    response::v2::{
+        health::OverallHealthStatus,
+        member::{EntityType, ProjectMember, Role},
+        project::{
+            Artifact, Project, ProjectDeletable, ProjectSummary,
+            types::{LabelScope, NativeReportSummary, ProjectMetadata, ScanStatus, Severity},
+        },
+        registry::{Registry, RegistryInfo},
+        repository::Repository,
+        robot::{Action, Resource, Robot, RobotCreated, RobotDuration, RobotLevel},
+        scan::VulnerabilityReports,
+        search::Search,
+        statistics::Statistic,
+        user::{Permission, User},
+        usergroup::{GroupType, UserGroup},
+    },
+};
+
+/// The Harbor versions with a directory of responses in `fixtures/`.
+const VERSIONS: [&str; 5] = ["v2.8", "v2.9", "v2.10", "v2.11", "v2.12"];
+
+/// Assert the response in `file` parses into `T` for every Harbor version, listing the versions
+/// that fail and why.
+fn assert_parses<T: DeserializeOwned>(file: &str) {
+    let failures: Vec<String> = VERSIONS
+        .iter()
+        .filter_map(|version| {
+            let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "fixtures", version, file]
+                .iter()
+                .collect();
+            let json = fs::read_to_string(&path).expect("fixture should be readable");
+            serde_json::from_str::<T>(&json)
+                .err()
+                .map(|e| format!("{version}/{file}: {e}"))
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} failed to parse:\n{}",
+        std::any::type_name::<T>(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn artifact_parses() {
+    assert_parses::<Artifact>("artifact.json");
+}
+
+#[test]
+fn overall_health_status_parses() {
+    assert_parses::<OverallHealthStatus>("health.json");
+}
+
+#[test]
+fn project_parses() {
+    assert_parses::<Project>("project.json");
+}
+
+#[test]
+fn projects_parse() {
+    assert_parses::<Vec<Project>>("projects.json");
+}
+
+#[test]
+fn project_deletable_parses() {
+    assert_parses::<ProjectDeletable>("project_deletable.json");
+}
+
+#[test]
+fn project_summary_parses() {
+    assert_parses::<ProjectSummary>("project_summary.json");
+}
+
+#[test]
+fn registry_parses() {
+    assert_parses::<Registry>("registry.json");
+}
+
+#[test]
+fn repository_parses() {
+    assert_parses::<Repository>("repository.json");
+}
+
+#[test]
+fn search_parses() {
+    assert_parses::<Search>("search.json");
+}
+
+#[test]
+fn statistic_parses() {
+    assert_parses::<Statistic>("statistics.json");
+}
+
+#[test]
+fn vulnerability_reports_parse() {
+    assert_parses::<VulnerabilityReports>("vulnerabilities.json");
+}
+
+#[test]
+fn native_report_summary_parses_from_scan_overview() {
+    let json = include_str!("../../../fixtures/v2.12/artifact.json");
+    let artifact: Artifact = serde_json::from_str(json).unwrap();
+    let summaries: Vec<NativeReportSummary> =
+        artifact.scan_overview.unwrap().into_values().collect();
+    assert_eq!(1, summaries.len());
+    assert_eq!(Some(Severity::Critical), summaries[0].severity);
+    assert_eq!(Some(ScanStatus::Success), summaries[0].scan_status);
+}
+
+#[test]
+fn project_metadata_parses_string_booleans() {
+    let json = r#"{"public":"true","severity":"high","auto_scan":"false"}"#;
+    let metadata: ProjectMetadata = serde_json::from_str(json).unwrap();
+    assert!(metadata.public);
+    assert_eq!(Some(false), metadata.auto_scan);
+    assert_eq!(None, metadata.prevent_vul);
+    assert_eq!(Some(Severity::High), metadata.severity);
+}
+
+#[test]
+fn artifact_label_scope_parses() {
+    let json = include_str!("../../../fixtures/v2.12/artifact.json");
+    let artifact: Artifact = serde_json::from_str(json).unwrap();
+    let label = &artifact.labels.unwrap()[0];
+    assert_eq!(Some(LabelScope::Global), label.scope);
+    assert_eq!("g", LabelScope::Global.to_string());
+}
+
+#[test]
+fn project_member_role_parses_from_id() {
+    let json = r#"{
+        "id": 3,
+        "project_id": 1,
+        "entity_name": "qa",
+        "role_name": "limitedGuest",
+        "role_id": 5,
+        "entity_id": 2,
+        "entity_type": "g"
+    }"#;
+    let member: ProjectMember = serde_json::from_str(json).unwrap();
+    assert_eq!(Role::LimitedGuest, member.role_id);
+    assert_eq!(Some(EntityType::Group), member.entity_type);
+    assert_eq!(Role::Other(9), Role::from(9));
+    assert_eq!(4, i64::from(Role::Maintainer));
+}
+
+#[test]
+fn robot_parses_with_typed_permissions() {
+    let json = r#"{
+        "id": 12,
+        "name": "robot$library+ci",
+        "description": "",
+        "secret": "",
+        "level": "project",
+        "duration": 30,
+        "editable": true,
+        "disable": false,
+        "expires_at": 1735689600,
+        "permissions": [{
+            "kind": "project",
+            "namespace": "library",
+            "access": [
+                {"resource": "repository", "action": "pull", "effect": ""},
+                {"resource": "export-cve", "action": "create", "effect": ""}
+            ]
+        }],
+        "creation_time": "2024-12-02T10:00:00.000Z",
+        "update_time": "2024-12-02T10:00:00.000Z"
+    }"#;
+    let robot: Robot = serde_json::from_str(json).unwrap();
+    assert_eq!(Some(RobotLevel::Project), robot.level);
+    assert_eq!(Some(RobotDuration::Days(30)), robot.duration);
+    let access = &robot.permissions[0].access;
+    assert_eq!(Resource::Repository, access[0].resource);
+    assert_eq!(Action::Pull, access[0].action);
+    assert_eq!(
+        Resource::Other("export-cve".to_string()),
+        access[1].resource
+    );
+}
+
+#[test]
+fn created_robot_secret_is_not_printed() {
+    let json = r#"{
+        "id": 12,
+        "name": "robot$library+ci",
+        "secret": "Sup3r-Secret",
+        "creation_time": "2024-12-02T10:00:00.000Z",
+        "expires_at": -1
+    }"#;
+    let robot: RobotCreated = serde_json::from_str(json).unwrap();
+    assert_eq!("Sup3r-Secret", robot.secret);
+    assert!(!format!("{robot:?}").contains("Sup3r-Secret"));
+    assert_eq!(
+        Auth::basic("robot$library+ci", "Sup3r-Secret"),
+        robot.auth()
+    );
+}
+
+#[test]
+fn current_user_parses_without_cli_secret() {
+    let json = r#"{
+        "user_id": 5,
+        "username": "alice",
+        "email": "alice@example.com",
+        "realname": "Alice",
+        "comment": "",
+        "sysadmin_flag": false,
+        "admin_role_in_auth": true,
+        "oidc_user_meta": {
+            "id": 1,
+            "user_id": 5,
+            "secret": "cli-secret",
+            "subiss": "alicehttps://sso.example.com",
+            "creation_time": "2024-03-01T08:00:00.000Z",
+            "update_time": "2024-03-01T08:00:00.000Z"
+        },
+        "creation_time": "2024-03-01T08:00:00.000Z",
+        "update_time": "2024-03-01T08:00:00.000Z"
+    }"#;
+    let user: User = serde_json::from_str(json).unwrap();
+    assert!(user.admin_role_in_auth);
+    assert!(!format!("{user:?}").contains("cli-secret"));
+
+    let json = r#"{"id": 3, "group_name": "qa", "group_type": 1, "ldap_group_dn": "cn=qa"}"#;
+    let group: UserGroup = serde_json::from_str(json).unwrap();
+    assert_eq!(Some(GroupType::Ldap), group.group_type);
+}
+
+#[test]
+fn registry_info_parses() {
+    let json = r#"{
+        "type": "docker-hub",
+        "description": "",
+        "supported_resource_filters": [
+            {"type": "name", "style": "input"},
+            {"type": "tag", "style": "input"}
+        ],
+        "supported_triggers": ["manual", "scheduled"]
+    }"#;
+    let info: RegistryInfo = serde_json::from_str(json).unwrap();
+    assert_eq!("docker-hub", info.kind);
+    assert_eq!("tag", info.supported_resource_filters[1].kind);
+    assert_eq!(vec!["manual", "scheduled"], info.supported_triggers);
+}
+
+#[test]
+fn repository_name_is_stripped_of_the_project() {
+    let json = r#"{"id": 1, "project_id": 1, "name": "library/team/nginx"}"#;
+    let repository: Repository = serde_json::from_str(json).unwrap();
+    assert_eq!("team/nginx", repository.repository_name());
+}
+
+#[test]
+fn current_user_permissions_keep_scoped_resources() {
+    let json = r#"[
+        {"resource": "/project/1/repository", "action": "push"},
+        {"resource": "/project/1/repository", "action": "pull"}
+    ]"#;
+    let permissions: Vec<Permission> = serde_json::from_str(json).unwrap();
+    assert_eq!("/project/1/repository", permissions[0].resource);
+    assert_eq!(Action::Push, permissions[0].action);
+}
+
+#[test]
+fn invalid_string_boolean_is_rejected() {
+    let json = r#"{"public":"yes"}"#;
+    assert!(serde_json::from_str::<ProjectMetadata>(json).is_err());
+}
+
+#[test]
+fn severities_are_ordered_and_unknown_values_kept() {
+    assert!(Severity::Critical > Severity::High);
+    assert!(Severity::Low > Severity::Negligible);
+    assert!(Severity::Negligible > Severity::Unknown);
+    assert_eq!(
+        Severity::Negligible,
+        Severity::from("Negligible".to_string())
+    );
+
+    let other = Severity::from("Severe".to_string());
+    assert_eq!(Severity::Other("Severe".to_string()), other);
+    assert!(other > Severity::Unknown && other < Severity::Negligible);
+    assert_eq!(r#""Severe""#, serde_json::to_string(&other).unwrap());
+    assert_eq!(
+        r#""Medium""#,
+        serde_json::to_string(&Severity::Medium).unwrap()
+    );
+}
+
+#[test]
+fn unknown_scan_status_is_kept() {
+    let status: ScanStatus = serde_json::from_str(r#""Not Scanned""#).unwrap();
+    assert_eq!(ScanStatus::Other("Not Scanned".to_string()), status);
+    assert!(!status.is_finished());
+    assert!(ScanStatus::Success.is_finished());
+}
+
+#[test]
+fn vulnerabilities_are_filtered_by_severity() {
+    let json = include_str!("../../../fixtures/v2.12/vulnerabilities.json");
+    let reports: VulnerabilityReports = serde_json::from_str(json).unwrap();
+    let report = reports.into_values().next().unwrap();
+    let blocking: Vec<&str> = report
+        .at_least(Severity::High)
+        .map(|vulnerability| vulnerability.id.as_str())
+        .collect();
+    assert_eq!(vec!["CVE-2023-44487", "CVE-2023-45853"], blocking);
+    assert_eq!(
+        Some(9.8),
+        report.vulnerabilities[2]
+            .preferred_cvss
+            .as_ref()
+            .unwrap()
+            .score_v3
+    );
+}
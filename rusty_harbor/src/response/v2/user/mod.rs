@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::response::v2::robot::Action;
+
+/// Response to requests that request user(s):
+/// - [`GetUser`](crate::request::v2::user::get::GetUser)
+/// - [`GetCurrentUser`](crate::request::v2::user::get::GetCurrentUser)
+/// - [`GetUsers`](crate::request::v2::user::get::GetUsers) (in a vector)
+#[derive(Debug, Deserialize)]
+pub struct User {
+    /// The ID of the user.
+    pub user_id: i64,
+    /// The name of the user.
+    pub username: String,
+    /// The email of the user.
+    pub email: Option<String>,
+    /// The real name of the user.
+    pub realname: Option<String>,
+    /// The comment of the user.
+    pub comment: Option<String>,
+    /// Whether the user is a system administrator.
+    #[serde(default)]
+    pub sysadmin_flag: bool,
+    /// Whether the user is an administrator by the admin group of the authentication backend
+    /// (LDAP or OIDC), rather than by its sysadmin flag.
+    #[serde(default)]
+    pub admin_role_in_auth: bool,
+    /// The OIDC metadata of the user, if Harbor authenticates with OIDC.
+    pub oidc_user_meta: Option<OidcUserMeta>,
+    /// The creation time of the user.
+    pub creation_time: Option<DateTime<Utc>>,
+    /// The update time of the user.
+    pub update_time: Option<DateTime<Utc>>,
+}
+
+/// The OIDC metadata of a user. The CLI secret is left out on purpose.
+#[derive(Debug, Deserialize)]
+pub struct OidcUserMeta {
+    /// The ID of the metadata.
+    pub id: Option<i64>,
+    /// The ID of the user.
+    pub user_id: Option<i64>,
+    /// The concatenation of the subject and the issuer of the ID token of the user.
+    pub subiss: Option<String>,
+    /// The creation time of the metadata.
+    pub creation_time: Option<DateTime<Utc>>,
+    /// The update time of the metadata.
+    pub update_time: Option<DateTime<Utc>>,
+}
+
+/// Response to the [`SearchUsers`](crate::request::v2::user::get::SearchUsers) request (in a
+/// vector).
+#[derive(Debug, Deserialize)]
+pub struct UserSearchResult {
+    /// The ID of the user.
+    pub user_id: i64,
+    /// The name of the user.
+    pub username: String,
+}
+
+/// Response to the
+/// [`GetCurrentUserPermissions`](crate::request::v2::user::get::GetCurrentUserPermissions) request
+/// (in a vector).
+#[derive(Debug, Deserialize)]
+pub struct Permission {
+    /// The resource of the permission, a path containing its scope, e.g.
+    /// "/project/1/repository", or only "repository" when the permissions were requested
+    /// relative to the scope.
+    pub resource: String,
+    /// The action of the permission.
+    pub action: Action,
+}
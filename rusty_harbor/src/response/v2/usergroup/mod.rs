@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// Response to requests that request user group(s):
+/// - [`GetUserGroup`](crate::request::v2::usergroup::get::GetUserGroup)
+/// - [`GetUserGroups`](crate::request::v2::usergroup::get::GetUserGroups) (in a vector)
+#[derive(Debug, Deserialize)]
+pub struct UserGroup {
+    /// The ID of the user group.
+    pub id: i64,
+    /// The name of the user group.
+    pub group_name: String,
+    /// The type of the user group.
+    pub group_type: Option<GroupType>,
+    /// The DN of the LDAP group if group type is LDAP.
+    pub ldap_group_dn: Option<String>,
+}
+
+/// Response to the [`SearchUserGroups`](crate::request::v2::usergroup::get::SearchUserGroups)
+/// request (in a vector).
+#[derive(Debug, Deserialize)]
+pub struct UserGroupSearchResult {
+    /// The ID of the user group.
+    pub id: i64,
+    /// The name of the user group.
+    pub group_name: String,
+    /// The type of the user group.
+    pub group_type: Option<GroupType>,
+}
+
+/// The authentication backend a user group comes from. Harbor identifies group types by ID.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(from = "i64", into = "i64")]
+pub enum GroupType {
+    Ldap,
+    Http,
+    Oidc,
+    /// Any group type that is not covered by the other variants, by ID.
+    Other(i64),
+}
+
+impl From<i64> for GroupType {
+    fn from(id: i64) -> Self {
+        match id {
+            1 => GroupType::Ldap,
+            2 => GroupType::Http,
+            3 => GroupType::Oidc,
+            _ => GroupType::Other(id),
+        }
+    }
+}
+
+impl From<GroupType> for i64 {
+    fn from(group_type: GroupType) -> Self {
+        match group_type {
+            GroupType::Ldap => 1,
+            GroupType::Http => 2,
+            GroupType::Oidc => 3,
+            GroupType::Other(id) => id,
+        }
+    }
+}
@@ -0,0 +1,734 @@
+//! An in-process mock of the Harbor API, to test Harbor integrations without a Harbor instance.
+//!
+//! The [`MockHarbor`] is seeded with two projects (the public [`PROJECT_NAME`] and a private
+//! `team` project), their artifacts, a healthy health status and statistics. Scanning an artifact
+//! completes immediately with a seeded vulnerability report. It records every request it
+//! receives, so tests can assert on what was sent.
+//!
+//! Requires the `testing` feature.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use axum::{
+    Json, Router,
+    body::{Body, to_bytes},
+    extract::{Path, Query, Request, State},
+    http::{
+        HeaderMap, HeaderValue, Method, StatusCode, Uri,
+        header::{CONTENT_TYPE, LOCATION},
+    },
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use percent_encoding::percent_decode_str;
+use serde_json::{Value, json};
+use tokio::{net::TcpListener, task::JoinHandle};
+
+use crate::{
+    client::{HarborClient, auth::Auth, error::ClientError},
+    response::{X_REQUEST_ID, X_TOTAL_COUNT},
+};
+
+/// Name of the seeded public project, which contains the `nginx` and `alpine` repositories.
+pub const PROJECT_NAME: &str = "library";
+
+/// Username the [`client`](MockHarbor::client) of the mock authenticates with.
+pub const USERNAME: &str = "admin";
+
+/// Password the [`client`](MockHarbor::client) of the mock authenticates with.
+pub const PASSWORD: &str = "Harbor12345";
+
+const PROJECTS: &str = include_str!("testing/projects.json");
+const ARTIFACTS: &str = include_str!("testing/artifacts.json");
+const HEALTH: &str = include_str!("testing/health.json");
+const STATISTICS: &str = include_str!("testing/statistics.json");
+const VULNERABILITIES: &str = include_str!("testing/vulnerabilities.json");
+
+/// The MIME type of the vulnerability report the mock serves.
+const REPORT_MIME_TYPE: &str = "application/vnd.security.vulnerability.report; version=1.1";
+
+/// A request received by the [`MockHarbor`].
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: Method,
+    /// The path of the request, e.g. `/api/v2.0/projects`.
+    pub path: String,
+    /// The query string of the request, if any.
+    pub query: Option<String>,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// The value of a header, if present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+/// The resources served by the mock, as Harbor JSON.
+#[derive(Default)]
+struct Store {
+    projects: Vec<Value>,
+    artifacts: Vec<Value>,
+    health: Value,
+    statistics: Value,
+    requests: Vec<RecordedRequest>,
+}
+
+type Shared = Arc<Mutex<Store>>;
+
+fn lock(store: &Shared) -> MutexGuard<'_, Store> {
+    store.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A mock Harbor API, served over HTTP on a random local port until it is dropped.
+pub struct MockHarbor {
+    url: String,
+    store: Shared,
+    server: JoinHandle<()>,
+}
+
+impl MockHarbor {
+    /// Start a mock seeded with the default fixtures.
+    pub async fn start() -> Result<Self, ClientError> {
+        MockHarbor::builder().start().await
+    }
+
+    /// Create a builder to seed the mock with other fixtures.
+    pub fn builder() -> MockHarborBuilder {
+        MockHarborBuilder::default()
+    }
+
+    /// The base url of the mock, to create a [`HarborClient`] with.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// A client for the mock, authenticating as [`USERNAME`] with [`PASSWORD`].
+    pub fn client(&self) -> HarborClient {
+        HarborClient::builder(&self.url)
+            .auth(Auth::basic(USERNAME, PASSWORD))
+            .no_proxy()
+            .build()
+            .expect("client for the mock Harbor")
+    }
+
+    /// All requests received so far, in the order they were received.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        lock(&self.store).requests.clone()
+    }
+}
+
+impl Drop for MockHarbor {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+/// Builder for a [`MockHarbor`], starting from the default fixtures.
+pub struct MockHarborBuilder {
+    store: Store,
+}
+
+impl Default for MockHarborBuilder {
+    fn default() -> Self {
+        MockHarborBuilder {
+            store: Store {
+                projects: fixture(PROJECTS),
+                artifacts: fixture(ARTIFACTS),
+                health: fixture(HEALTH),
+                statistics: fixture(STATISTICS),
+                requests: Vec::new(),
+            },
+        }
+    }
+}
+
+impl MockHarborBuilder {
+    /// Replace the seeded projects, e.g. with an empty vector.
+    pub fn projects(mut self, projects: Vec<Value>) -> Self {
+        self.store.projects = projects;
+        self
+    }
+
+    /// Add a project, in the JSON format of Harbor.
+    pub fn project(mut self, project: Value) -> Self {
+        self.store.projects.push(project);
+        self
+    }
+
+    /// Replace the seeded artifacts, e.g. with an empty vector.
+    pub fn artifacts(mut self, artifacts: Vec<Value>) -> Self {
+        self.store.artifacts = artifacts;
+        self
+    }
+
+    /// Add an artifact, in the JSON format of Harbor. Its `project_id` and `repository_name`
+    /// determine where it is listed.
+    pub fn artifact(mut self, artifact: Value) -> Self {
+        self.store.artifacts.push(artifact);
+        self
+    }
+
+    /// Replace the health status, e.g. to report an unhealthy component.
+    pub fn health(mut self, health: Value) -> Self {
+        self.store.health = health;
+        self
+    }
+
+    /// Replace the statistics.
+    pub fn statistics(mut self, statistics: Value) -> Self {
+        self.store.statistics = statistics;
+        self
+    }
+
+    /// Start serving the mock on a random local port.
+    pub async fn start(self) -> Result<MockHarbor, ClientError> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let store = Arc::new(Mutex::new(self.store));
+
+        let app = Router::new()
+            .route("/api/v2.0/health", get(health))
+            .route("/api/v2.0/statistics", get(statistics))
+            .route("/api/v2.0/search", get(search))
+            .route(
+                "/api/v2.0/projects",
+                get(list_projects).head(head_project).post(create_project),
+            )
+            .route(
+                "/api/v2.0/projects/{project}",
+                get(get_project).delete(delete_project),
+            )
+            .route("/api/v2.0/projects/{project}/summary", get(project_summary))
+            .route(
+                "/api/v2.0/projects/{project}/artifacts",
+                get(project_artifacts),
+            )
+            .route(
+                "/api/v2.0/projects/{project}/repositories",
+                get(project_repositories),
+            )
+            .route(
+                "/api/v2.0/projects/{project}/repositories/{repository}/artifacts",
+                get(repository_artifacts),
+            )
+            .route(
+                "/api/v2.0/projects/{project}/repositories/{repository}/artifacts/{reference}",
+                get(get_artifact),
+            )
+            .route(
+                "/api/v2.0/projects/{project}/repositories/{repository}/artifacts/{reference}/additions/vulnerabilities",
+                get(vulnerabilities),
+            )
+            .route(
+                "/api/v2.0/projects/{project}/repositories/{repository}/artifacts/{reference}/scan",
+                post(scan_artifact),
+            )
+            .route(
+                "/api/v2.0/projects/{project}/repositories/{repository}/artifacts/{reference}/scan/stop",
+                post(stop_scan_artifact),
+            )
+            .route(
+                "/api/v2.0/projects/{project}/repositories/{repository}/artifacts/{reference}/scan/{report_id}/log",
+                get(scan_report_log),
+            )
+            .fallback(|| async { error(StatusCode::NOT_FOUND, "NOT_FOUND", "not found") })
+            .layer(middleware::from_fn_with_state(store.clone(), record))
+            .with_state(store.clone());
+
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        Ok(MockHarbor { url, store, server })
+    }
+}
+
+fn fixture<T: serde::de::DeserializeOwned>(json: &str) -> T {
+    serde_json::from_str(json).expect("valid fixture")
+}
+
+/// Record the request and echo its `X-Request-Id`, like Harbor does.
+async fn record(State(store): State<Shared>, request: Request, next: Next) -> Response {
+    let (parts, body) = request.into_parts();
+    let body = to_bytes(body, usize::MAX).await.unwrap_or_default();
+
+    let request_id = {
+        let mut store = lock(&store);
+        store.requests.push(RecordedRequest {
+            method: parts.method.clone(),
+            path: parts.uri.path().to_string(),
+            query: parts.uri.query().map(str::to_string),
+            headers: parts.headers.clone(),
+            body: String::from_utf8_lossy(&body).into_owned(),
+        });
+        parts
+            .headers
+            .get(X_REQUEST_ID)
+            .cloned()
+            .unwrap_or_else(|| HeaderValue::from(store.requests.len()))
+    };
+
+    let mut response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    response.headers_mut().insert(X_REQUEST_ID, request_id);
+    response
+}
+
+/// A response with the error payload of Harbor.
+fn error(status: StatusCode, code: &str, message: &str) -> Response {
+    let errors = json!({ "errors": [{ "code": code, "message": message }] });
+    (status, Json(errors)).into_response()
+}
+
+fn project_not_found(project: &str) -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "NOT_FOUND",
+        &format!("project {project} not found"),
+    )
+}
+
+/// Respond with a page of `items`, with the `X-Total-Count` and `Link` headers Harbor sets. The
+/// link keeps the path of the request as it was sent, e.g. with double encoded repository names.
+fn page(uri: &Uri, items: Vec<Value>) -> Response {
+    let mut query: Vec<(String, String)> =
+        serde_urlencoded::from_str(uri.query().unwrap_or_default()).unwrap_or_default();
+    let number = |key: &str, default: usize| {
+        query
+            .iter()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, value)| value.parse().ok())
+            .filter(|&value: &usize| value > 0)
+            .unwrap_or(default)
+    };
+    let (page, page_size) = (number("page", 1), number("page_size", 10));
+
+    let total = items.len();
+    let items: Vec<Value> = items
+        .into_iter()
+        .skip((page - 1) * page_size)
+        .take(page_size)
+        .collect();
+
+    let mut response = Json(items).into_response();
+    let headers = response.headers_mut();
+    headers.insert(X_TOTAL_COUNT, HeaderValue::from(total));
+
+    if page * page_size < total {
+        query.retain(|(key, _)| key != "page" && key != "page_size");
+        query.push(("page".to_string(), (page + 1).to_string()));
+        query.push(("page_size".to_string(), page_size.to_string()));
+        query.sort();
+        let query = serde_urlencoded::to_string(query).unwrap_or_default();
+        let path = uri.path();
+        if let Ok(link) = HeaderValue::from_str(&format!("<{path}?{query}>; rel=\"next\"")) {
+            headers.insert("link", link);
+        }
+    }
+
+    response
+}
+
+fn name(value: &Value) -> &str {
+    value["name"].as_str().unwrap_or_default()
+}
+
+/// Whether the project in the path is a name, even if it looks like an ID.
+fn is_resource_name(headers: &HeaderMap) -> bool {
+    headers
+        .get("X-Is-Resource-Name")
+        .is_some_and(|value| value == "true")
+}
+
+/// Find a project by its name, or by its ID unless `is_name` is set.
+fn find_project(store: &Store, project: &str, is_name: bool) -> Option<Value> {
+    store
+        .projects
+        .iter()
+        .find(|p| match project.parse::<i64>() {
+            Ok(id) if !is_name => p["project_id"].as_i64() == Some(id),
+            _ => name(p) == project,
+        })
+        .cloned()
+}
+
+/// The artifacts of a project, optionally only those in one of its repositories.
+fn artifacts_of(store: &Store, project: &Value, repository: Option<&str>) -> Vec<Value> {
+    let repository_name = repository.map(|repository| {
+        // Repository names are double encoded in the path
+        let repository = percent_decode_str(repository).decode_utf8_lossy();
+        format!("{}/{repository}", name(project))
+    });
+
+    store
+        .artifacts
+        .iter()
+        .filter(|a| a["project_id"] == project["project_id"])
+        .filter(|a| {
+            repository_name
+                .as_deref()
+                .is_none_or(|name| a["repository_name"] == name)
+        })
+        .cloned()
+        .collect()
+}
+
+/// The repositories of a project, derived from its artifacts.
+fn repositories_of(store: &Store, project: &Value) -> Vec<Value> {
+    let mut repositories: Vec<Value> = Vec::new();
+
+    for artifact in artifacts_of(store, project, None) {
+        let name = &artifact["repository_name"];
+        match repositories.iter_mut().find(|r| &r["name"] == name) {
+            Some(repository) => {
+                let count = repository["artifact_count"].as_i64().unwrap_or_default();
+                repository["artifact_count"] = json!(count + 1);
+            }
+            None => repositories.push(json!({
+                "id": artifact["repository_id"],
+                "project_id": artifact["project_id"],
+                "name": name,
+                "artifact_count": 1,
+                "pull_count": 0,
+                "creation_time": artifact["push_time"],
+                "update_time": artifact["push_time"],
+            })),
+        }
+    }
+
+    repositories
+}
+
+async fn health(State(store): State<Shared>) -> Json<Value> {
+    Json(lock(&store).health.clone())
+}
+
+async fn statistics(State(store): State<Shared>) -> Json<Value> {
+    Json(lock(&store).statistics.clone())
+}
+
+async fn search(
+    State(store): State<Shared>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    let store = lock(&store);
+    let q = query.get("q").map(String::as_str).unwrap_or_default();
+
+    let projects: Vec<&Value> = store
+        .projects
+        .iter()
+        .filter(|p| name(p).contains(q))
+        .collect();
+
+    let repositories: Vec<Value> = store
+        .projects
+        .iter()
+        .flat_map(|project| {
+            repositories_of(&store, project)
+                .into_iter()
+                .filter(|r| name(r).contains(q))
+                .map(move |repository| {
+                    json!({
+                        "project_id": project["project_id"],
+                        "project_name": project["name"],
+                        "project_public": project["metadata"]["public"] == "true",
+                        "repository_name": repository["name"],
+                        "pull_count": repository["pull_count"],
+                        "artifact_count": repository["artifact_count"],
+                    })
+                })
+        })
+        .collect();
+
+    Json(json!({ "project": projects, "repository": repositories }))
+}
+
+async fn list_projects(
+    State(store): State<Shared>,
+    Query(query): Query<HashMap<String, String>>,
+    uri: Uri,
+) -> Response {
+    let projects = lock(&store)
+        .projects
+        .iter()
+        .filter(|p| {
+            query
+                .get("name")
+                .is_none_or(|n| name(p).contains(n.as_str()))
+        })
+        .filter(|p| {
+            query
+                .get("public")
+                .is_none_or(|public| p["metadata"]["public"] == public.as_str())
+        })
+        .cloned()
+        .collect();
+
+    page(&uri, projects)
+}
+
+async fn head_project(
+    State(store): State<Shared>,
+    Query(query): Query<HashMap<String, String>>,
+) -> StatusCode {
+    let exists = query.get("project_name").is_some_and(|project| {
+        lock(&store)
+            .projects
+            .iter()
+            .any(|p| name(p) == project.as_str())
+    });
+
+    match exists {
+        true => StatusCode::OK,
+        false => StatusCode::NOT_FOUND,
+    }
+}
+
+async fn create_project(State(store): State<Shared>, Json(request): Json<Value>) -> Response {
+    let Some(project_name) = request["project_name"].as_str() else {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "BAD_REQUEST",
+            "project_name is required",
+        );
+    };
+
+    let mut store = lock(&store);
+    if store.projects.iter().any(|p| name(p) == project_name) {
+        let message = format!("The project named {project_name} already exists");
+        return error(StatusCode::CONFLICT, "CONFLICT", &message);
+    }
+
+    let id = store
+        .projects
+        .iter()
+        .filter_map(|p| p["project_id"].as_i64())
+        .max()
+        .unwrap_or_default()
+        + 1;
+    let now = chrono::Utc::now();
+    let metadata = match &request["metadata"] {
+        Value::Null => json!({ "public": "false" }),
+        metadata => metadata.clone(),
+    };
+
+    store.projects.push(json!({
+        "project_id": id,
+        "owner_id": 1,
+        "name": project_name,
+        "registry_id": request["registry_id"],
+        "creation_time": now,
+        "update_time": now,
+        "deleted": false,
+        "owner_name": USERNAME,
+        "repo_count": 0,
+        "metadata": metadata,
+        "cve_allowlist": {
+            "id": id,
+            "project_id": id,
+            "items": [],
+            "creation_time": now,
+            "update_time": now,
+        },
+    }));
+
+    let location = format!("/api/v2.0/projects/{id}");
+    (StatusCode::CREATED, [(LOCATION, location)]).into_response()
+}
+
+async fn get_project(
+    State(store): State<Shared>,
+    Path(project): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    match find_project(&lock(&store), &project, is_resource_name(&headers)) {
+        Some(project) => Json(project).into_response(),
+        None => project_not_found(&project),
+    }
+}
+
+async fn delete_project(
+    State(store): State<Shared>,
+    Path(project): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    let mut store = lock(&store);
+    let Some(found) = find_project(&store, &project, is_resource_name(&headers)) else {
+        return project_not_found(&project);
+    };
+
+    store
+        .projects
+        .retain(|p| p["project_id"] != found["project_id"]);
+    StatusCode::OK.into_response()
+}
+
+async fn project_summary(
+    State(store): State<Shared>,
+    Path(project): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    let store = lock(&store);
+    let Some(project) = find_project(&store, &project, is_resource_name(&headers)) else {
+        return project_not_found(&project);
+    };
+
+    Json(json!({
+        "repo_count": repositories_of(&store, &project).len(),
+        "project_admin_count": 1,
+        "maintainer_count": 0,
+        "developer_count": 0,
+        "guest_count": 0,
+        "limited_guest_count": 0,
+    }))
+    .into_response()
+}
+
+async fn project_artifacts(
+    State(store): State<Shared>,
+    Path(project): Path<String>,
+    uri: Uri,
+    headers: HeaderMap,
+) -> Response {
+    let store = lock(&store);
+    let Some(found) = find_project(&store, &project, is_resource_name(&headers)) else {
+        return project_not_found(&project);
+    };
+
+    page(&uri, artifacts_of(&store, &found, None))
+}
+
+async fn project_repositories(
+    State(store): State<Shared>,
+    Path(project): Path<String>,
+    uri: Uri,
+    headers: HeaderMap,
+) -> Response {
+    let store = lock(&store);
+    let Some(found) = find_project(&store, &project, is_resource_name(&headers)) else {
+        return project_not_found(&project);
+    };
+
+    page(&uri, repositories_of(&store, &found))
+}
+
+async fn repository_artifacts(
+    State(store): State<Shared>,
+    Path((project, repository)): Path<(String, String)>,
+    uri: Uri,
+) -> Response {
+    let store = lock(&store);
+    let Some(found) = find_project(&store, &project, true) else {
+        return project_not_found(&project);
+    };
+
+    page(&uri, artifacts_of(&store, &found, Some(&repository)))
+}
+
+/// Find an artifact by its digest or one of its tags, returning its index in the store.
+fn find_artifact(store: &Store, project: &str, repository: &str, reference: &str) -> Option<usize> {
+    let project = find_project(store, project, true)?;
+    let repository = percent_decode_str(repository).decode_utf8_lossy();
+    let repository_name = format!("{}/{repository}", name(&project));
+
+    store.artifacts.iter().position(|a| {
+        a["project_id"] == project["project_id"]
+            && a["repository_name"] == repository_name.as_str()
+            && (a["digest"] == reference
+                || a["tags"]
+                    .as_array()
+                    .is_some_and(|tags| tags.iter().any(|t| name(t) == reference)))
+    })
+}
+
+fn artifact_not_found(project: &str, repository: &str, reference: &str) -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "NOT_FOUND",
+        &format!("artifact {project}/{repository}:{reference} not found"),
+    )
+}
+
+async fn get_artifact(
+    State(store): State<Shared>,
+    Path((project, repository, reference)): Path<(String, String, String)>,
+) -> Response {
+    let store = lock(&store);
+    match find_artifact(&store, &project, &repository, &reference) {
+        Some(index) => Json(store.artifacts[index].clone()).into_response(),
+        None => artifact_not_found(&project, &repository, &reference),
+    }
+}
+
+/// Scanning completes immediately, adding a successful scan overview to the artifact.
+async fn scan_artifact(
+    State(store): State<Shared>,
+    Path((project, repository, reference)): Path<(String, String, String)>,
+) -> Response {
+    let mut store = lock(&store);
+    let Some(index) = find_artifact(&store, &project, &repository, &reference) else {
+        return artifact_not_found(&project, &repository, &reference);
+    };
+
+    let report: Value = fixture(VULNERABILITIES);
+    let report = &report[REPORT_MIME_TYPE];
+    let now = chrono::Utc::now();
+    store.artifacts[index]["scan_overview"] = json!({
+        REPORT_MIME_TYPE: {
+            "report_id": format!("report-{}", store.artifacts[index]["id"]),
+            "scan_status": "Success",
+            "severity": report["severity"],
+            "duration": 0,
+            "start_time": now,
+            "end_time": now,
+            "complete_percent": 100,
+            "scanner": report["scanner"],
+        }
+    });
+
+    StatusCode::ACCEPTED.into_response()
+}
+
+async fn stop_scan_artifact(
+    State(store): State<Shared>,
+    Path((project, repository, reference)): Path<(String, String, String)>,
+) -> Response {
+    match find_artifact(&lock(&store), &project, &repository, &reference) {
+        Some(_) => StatusCode::ACCEPTED.into_response(),
+        None => artifact_not_found(&project, &repository, &reference),
+    }
+}
+
+/// The seeded vulnerability report for scanned artifacts, or nothing for other artifacts.
+async fn vulnerabilities(
+    State(store): State<Shared>,
+    Path((project, repository, reference)): Path<(String, String, String)>,
+) -> Response {
+    let store = lock(&store);
+    let Some(index) = find_artifact(&store, &project, &repository, &reference) else {
+        return artifact_not_found(&project, &repository, &reference);
+    };
+
+    match store.artifacts[index]["scan_overview"] {
+        Value::Null => Json(json!({})).into_response(),
+        _ => Json(fixture::<Value>(VULNERABILITIES)).into_response(),
+    }
+}
+
+async fn scan_report_log(
+    State(store): State<Shared>,
+    Path((project, repository, reference, report_id)): Path<(String, String, String, String)>,
+) -> Response {
+    if find_artifact(&lock(&store), &project, &repository, &reference).is_none() {
+        return artifact_not_found(&project, &repository, &reference);
+    }
+
+    let log = format!("scan job for report {report_id} finished successfully\n");
+    ([(CONTENT_TYPE, "text/plain; charset=utf-8")], log).into_response()
+}